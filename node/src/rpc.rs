@@ -3,7 +3,8 @@
 use std::sync::Arc;
 
 use gasleap_runtime::{opaque::Block, AccountId, Balance, Index, BlockNumber};
-use sc_client_api::AuxStore;
+use sc_client_api::{AuxStore, BlockchainEvents};
+use sc_rpc::SubscriptionTaskExecutor;
 use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
@@ -19,6 +20,8 @@ pub struct FullDeps<C, P> {
 	pub pool: Arc<P>,
 	/// Whether to deny unsafe calls
 	pub deny_unsafe: DenyUnsafe,
+	/// Executor for RPC subscriptions
+	pub subscription_executor: SubscriptionTaskExecutor,
 }
 
 /// Instantiate all full RPC extensions.
@@ -29,22 +32,23 @@ where
 	C: ProvideRuntimeApi<Block>
 		+ HeaderBackend<Block>
 		+ AuxStore
+		+ BlockchainEvents<Block>
 		+ HeaderMetadata<Block, Error = BlockChainError>
 		+ Sync
 		+ Send
 		+ 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
-	C::Api: gasleap_sponsorship_rpc_runtime_api::SponsorshipApi<Block, AccountId, Balance, BlockNumber>,
+	C::Api: gasleap_sponsorship_rpc::SponsorshipRuntimeApi<Block, AccountId, Balance, BlockNumber>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + 'static,
 {
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
-	use pallet_sponsorship::rpc::{SponsorshipRpc, SponsorshipApiServer};
+	use gasleap_sponsorship_rpc::{SponsorshipApiServer, SponsorshipRpc};
 
 	let mut module = jsonrpsee::RpcModule::new(());
-	let FullDeps { client, pool, deny_unsafe } = deps;
+	let FullDeps { client, pool, deny_unsafe, subscription_executor } = deps;
 
 	// System RPC calls
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
@@ -53,7 +57,7 @@ where
 	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
 
 	// GasLeap Sponsorship RPC calls
-	module.merge(SponsorshipRpc::new(client.clone()).into_rpc())?;
+	module.merge(SponsorshipRpc::new(client.clone(), subscription_executor).into_rpc())?;
 
 	Ok(module)
 }
\ No newline at end of file
@@ -6,10 +6,19 @@ use codec::Codec;
 use sp_std::vec::Vec;
 
 // Re-export types from the pallet
-pub use pallet_sponsorship::{PoolId, PoolInfo, TransactionRecord};
+pub use pallet_sponsorship::{
+	DryRunResult, FeeBreakdown, PoolForecast, PoolId, PoolInfo, PoolStats, SponsorshipWindow,
+	TransactionRecord,
+};
 
 sp_api::decl_runtime_apis! {
 	/// The API to interact with sponsorship pallet.
+	///
+	/// Version history:
+	/// - 1: pool, history, savings and gas cost queries
+	/// - 2: single transaction lookup, fee breakdown, pool statistics, runway forecast,
+	///   sponsorship windows, dry-run and paginated history, whitelist counts
+	#[api_version(2)]
 	pub trait SponsorshipApi<AccountId, Balance, BlockNumber>
 	where
 		AccountId: Codec,
@@ -25,10 +34,63 @@ sp_api::decl_runtime_apis! {
 		/// Get transaction history for a pool
 		fn get_transaction_history(pool_id: PoolId, limit: u32) -> Vec<TransactionRecord<AccountId, Balance, BlockNumber>>;
 
+		/// Get a single transaction record by ID
+		#[api_version(2)]
+		fn get_transaction(transaction_id: u64) -> Option<TransactionRecord<AccountId, Balance, BlockNumber>>;
+
 		/// Get user gas savings
 		fn get_user_gas_savings(user: AccountId) -> Balance;
 
 		/// Estimate gas cost for a transaction
 		fn estimate_gas_cost(target_chain: u32, call_data: Vec<u8>) -> Balance;
+
+		/// Estimate gas cost for a transaction, split into its components
+		#[api_version(2)]
+		fn estimate_fee_breakdown(target_chain: u32, call_data: Vec<u8>) -> FeeBreakdown<Balance>;
+
+		/// Get pool statistics aggregated per chain and per day over a block range.
+		/// Both bounds are inclusive; omitted bounds default to the last year up to the current block.
+		#[api_version(2)]
+		fn get_pool_stats(
+			pool_id: PoolId,
+			from_block: Option<BlockNumber>,
+			to_block: Option<BlockNumber>,
+		) -> PoolStats<Balance>;
+
+		/// Project when a pool runs out at its recent spend rate, and the top-up
+		/// needed to last `target_runway_days`
+		#[api_version(2)]
+		fn forecast_pool_runway(
+			pool_id: PoolId,
+			target_runway_days: u32,
+		) -> Option<PoolForecast<Balance, BlockNumber>>;
+
+		/// Whether a pool's schedule is open now, and when it opens next if not
+		#[api_version(2)]
+		fn next_sponsorship_window(pool_id: PoolId) -> Option<SponsorshipWindow<BlockNumber>>;
+
+		/// Simulate sponsoring a transaction without committing it.
+		/// `credential` is the SCALE-encoded `SponsorshipCredential` the user would submit.
+		#[api_version(2)]
+		fn dry_run_sponsorship(
+			user: AccountId,
+			pool_id: PoolId,
+			target_chain: u32,
+			call_data: Vec<u8>,
+			credential: Option<Vec<u8>>,
+		) -> DryRunResult<Balance>;
+
+		/// Get a page of transaction history for a pool, newest first.
+		/// Returns the records and the cursor to pass for the next page.
+		#[api_version(2)]
+		fn get_transaction_history_paged(
+			pool_id: PoolId,
+			cursor: Option<u64>,
+			limit: u32,
+		) -> (Vec<TransactionRecord<AccountId, Balance, BlockNumber>>, Option<u64>);
+
+		/// Get the number of accounts on a pool's whitelist
+		#[api_version(2)]
+		fn get_whitelist_count(pool_id: PoolId) -> u32;
 	}
-}
\ No newline at end of file
+}
@@ -0,0 +1,715 @@
+//! RPC interface for the sponsorship pallet.
+//!
+//! Lives in its own crate so the `no_std` pallet does not pull in node-side dependencies.
+
+use std::{collections::BTreeMap, sync::Arc};
+use codec::Codec;
+use futures::{stream, FutureExt, StreamExt, TryStreamExt};
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::{
+		error::{CallError, ErrorObject, ErrorObjectOwned},
+		SubscriptionResult,
+	},
+	SubscriptionSink,
+};
+use sc_client_api::BlockchainEvents;
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiError, ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::{traits::SpawnNamed, Bytes};
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, Header as HeaderT, NumberFor},
+};
+
+use pallet_sponsorship::{DryRunResult, FeeBreakdown, PoolForecast, PoolId, PoolInfo, PoolStats, SponsorshipWindow, TransactionRecord, TransactionStatus};
+
+pub use gasleap_sponsorship_rpc_runtime_api::SponsorshipApi as SponsorshipRuntimeApi;
+
+/// Sponsorship RPC error codes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+	/// The runtime API call failed.
+	RuntimeError,
+	/// The runtime returned a value that could not be decoded.
+	DecodeError,
+	/// The requested block is not known to the node.
+	UnknownBlock,
+	/// The runtime does not provide the required `SponsorshipApi` version.
+	UnsupportedApiVersion,
+}
+
+impl Error {
+	/// Stable, machine-readable name of the error.
+	pub fn kind(&self) -> &'static str {
+		match self {
+			Error::RuntimeError => "RuntimeError",
+			Error::DecodeError => "DecodeError",
+			Error::UnknownBlock => "UnknownBlock",
+			Error::UnsupportedApiVersion => "UnsupportedApiVersion",
+		}
+	}
+
+	/// Human-readable message of the error.
+	pub fn message(&self) -> &'static str {
+		match self {
+			Error::RuntimeError => "Sponsorship runtime API call failed",
+			Error::DecodeError => "Unable to decode sponsorship runtime API result",
+			Error::UnknownBlock => "Requested block is unknown",
+			Error::UnsupportedApiVersion => "Runtime does not support this sponsorship API method",
+		}
+	}
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 8001,
+			Error::DecodeError => 8002,
+			Error::UnknownBlock => 8003,
+			Error::UnsupportedApiVersion => 8004,
+		}
+	}
+}
+
+impl From<&ApiError> for Error {
+	fn from(e: &ApiError) -> Self {
+		match e {
+			ApiError::UnknownBlock(_) => Error::UnknownBlock,
+			ApiError::FailedToDecodeReturnValue { .. } |
+			ApiError::FailedToConvertReturnValue { .. } |
+			ApiError::FailedToConvertParameter { .. } => Error::DecodeError,
+			_ => Error::RuntimeError,
+		}
+	}
+}
+
+/// Data attached to every sponsorship RPC error.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorDetails {
+	/// Machine-readable error name, matching [`Error::kind`].
+	pub kind: String,
+	/// Underlying error as reported by the node.
+	pub details: String,
+}
+
+/// Build the JSON-RPC error object for `error`, attaching `details` as structured data.
+pub fn rpc_error_object(error: Error, details: impl Into<String>) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		error.into(),
+		error.message(),
+		Some(ErrorDetails { kind: error.kind().into(), details: details.into() }),
+	)
+}
+
+/// Build the JSON-RPC error for `error`, attaching `details` as structured data.
+pub fn rpc_error(error: Error, details: impl Into<String>) -> JsonRpseeError {
+	JsonRpseeError::Call(CallError::Custom(rpc_error_object(error, details)))
+}
+
+/// Fail with `UnsupportedApiVersion` unless `version` is at least `required`.
+fn require_api_version(version: u32, required: u32, method: &str) -> Result<(), ErrorObjectOwned> {
+	if version < required {
+		return Err(rpc_error_object(
+			Error::UnsupportedApiVersion,
+			format!("{} requires SponsorshipApi v{}, runtime provides v{}", method, required, version),
+		))
+	}
+	Ok(())
+}
+
+/// Map a runtime API failure to a sponsorship RPC error.
+fn map_api_error(e: ApiError) -> JsonRpseeError {
+	rpc_error(Error::from(&e), format!("{:?}", e))
+}
+
+/// First `SponsorshipApi` version that provides single transaction lookups, fee
+/// breakdowns, pool stats, dry-runs and paginated history.
+const API_VERSION_V2: u32 = 2;
+
+/// A page of pool transaction history.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionHistoryPage<Record> {
+	/// Records on this page, newest first
+	pub items: Vec<Record>,
+	/// Cursor to request the next page with, if any
+	pub next_cursor: Option<u64>,
+}
+
+/// Number of newest pool transactions watched by `sponsorship_subscribePool`.
+const POOL_SUBSCRIPTION_WINDOW: u32 = 50;
+
+/// Runway used by `sponsorship_forecastRunway` when the caller does not ask for one.
+const DEFAULT_RUNWAY_DAYS: u32 = 30;
+
+/// A transaction status transition and the block in which it was observed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionStatusUpdate<BlockHash, BlockNumber> {
+	/// Sponsored transaction ID
+	pub transaction_id: u64,
+	/// Pool that sponsored the transaction
+	pub pool_id: PoolId,
+	/// Status after the transition
+	pub status: TransactionStatus,
+	/// Block in which the new status was first seen
+	pub block_hash: BlockHash,
+	/// Number of that block
+	pub block_number: BlockNumber,
+	/// Whether the block is finalized
+	pub finalized: bool,
+}
+
+#[rpc(client, server)]
+pub trait SponsorshipApi<BlockHash, AccountId, Balance, BlockNumber> {
+	/// Get pool information by ID
+	#[method(name = "sponsorship_getPool")]
+	fn get_pool(
+		&self,
+		pool_id: PoolId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<PoolInfo<AccountId, Balance, BlockNumber>>>;
+
+	/// Get pools owned by an account
+	#[method(name = "sponsorship_getPoolsByOwner")]
+	fn get_pools_by_owner(
+		&self,
+		owner: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(PoolId, PoolInfo<AccountId, Balance, BlockNumber>)>>;
+
+	/// Get transaction history for a pool
+	#[method(name = "sponsorship_getTransactionHistory")]
+	fn get_transaction_history(
+		&self,
+		pool_id: PoolId,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<TransactionRecord<AccountId, Balance, BlockNumber>>>;
+
+	/// Get user gas savings
+	#[method(name = "sponsorship_getUserGasSavings")]
+	fn get_user_gas_savings(
+		&self,
+		user: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Balance>;
+
+	/// Estimate gas cost for a transaction
+	#[method(name = "sponsorship_estimateGasCost")]
+	fn estimate_gas_cost(
+		&self,
+		target_chain: u32,
+		call_data: Vec<u8>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Balance>;
+
+	/// Estimate gas cost for a transaction, split into its components (requires API v2)
+	#[method(name = "sponsorship_estimateFeeBreakdown")]
+	fn estimate_fee_breakdown(
+		&self,
+		target_chain: u32,
+		call_data: Vec<u8>,
+		at: Option<BlockHash>,
+	) -> RpcResult<FeeBreakdown<Balance>>;
+
+	/// Get pool statistics over an inclusive block range, split per chain and per day (requires API v2)
+	#[method(name = "sponsorship_getPoolStats")]
+	fn get_pool_stats(
+		&self,
+		pool_id: PoolId,
+		from_block: Option<BlockNumber>,
+		to_block: Option<BlockNumber>,
+		at: Option<BlockHash>,
+	) -> RpcResult<PoolStats<Balance>>;
+
+	/// Project when a pool runs dry and the top-up needed for a target runway (requires API v2)
+	#[method(name = "sponsorship_forecastRunway")]
+	fn forecast_runway(
+		&self,
+		pool_id: PoolId,
+		target_runway_days: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<PoolForecast<Balance, BlockNumber>>>;
+
+	/// Get whether a pool's schedule is open, or when it opens next (requires API v2)
+	#[method(name = "sponsorship_nextSponsorshipWindow")]
+	fn next_sponsorship_window(
+		&self,
+		pool_id: PoolId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<SponsorshipWindow<BlockNumber>>>;
+
+	/// Simulate sponsoring a transaction without submitting it, optionally with the
+	/// SCALE-encoded credential the user would submit (requires API v2)
+	#[method(name = "sponsorship_dryRun")]
+	fn dry_run(
+		&self,
+		user: AccountId,
+		pool_id: PoolId,
+		target_chain: u32,
+		call_data: Vec<u8>,
+		credential: Option<Bytes>,
+		at: Option<BlockHash>,
+	) -> RpcResult<DryRunResult<Balance>>;
+
+	/// Get a page of transaction history for a pool, newest first (requires API v2)
+	#[method(name = "sponsorship_getTransactionHistoryPaged")]
+	fn get_transaction_history_paged(
+		&self,
+		pool_id: PoolId,
+		cursor: Option<u64>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<TransactionHistoryPage<TransactionRecord<AccountId, Balance, BlockNumber>>>;
+
+	/// Get the number of accounts on a pool's whitelist (requires API v2)
+	#[method(name = "sponsorship_getWhitelistCount")]
+	fn get_whitelist_count(&self, pool_id: PoolId, at: Option<BlockHash>) -> RpcResult<u32>;
+
+	/// Subscribe to status transitions of a single transaction (requires API v2)
+	#[subscription(
+		name = "sponsorship_subscribeTransaction" => "sponsorship_transactionStatus",
+		unsubscribe = "sponsorship_unsubscribeTransaction",
+		item = TransactionStatusUpdate<BlockHash, BlockNumber>
+	)]
+	fn subscribe_transaction(&self, tx_id: u64);
+
+	/// Subscribe to status transitions of a pool's newest transactions (requires API v2)
+	#[subscription(
+		name = "sponsorship_subscribePool" => "sponsorship_poolTransactionStatus",
+		unsubscribe = "sponsorship_unsubscribePool",
+		item = TransactionStatusUpdate<BlockHash, BlockNumber>
+	)]
+	fn subscribe_pool(&self, pool_id: PoolId);
+}
+
+/// A struct that implements the `SponsorshipApi`.
+pub struct SponsorshipRpc<C, Block> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	/// Executor used to drive subscription streams.
+	executor: Arc<dyn SpawnNamed>,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> SponsorshipRpc<C, Block> {
+	/// Create new `SponsorshipRpc` instance with the given reference to the client.
+	pub fn new(client: Arc<C>, executor: Arc<dyn SpawnNamed>) -> Self {
+		Self {
+			client,
+			executor,
+			_marker: Default::default(),
+		}
+	}
+}
+
+/// Tracks the last status seen for each transaction, separately for imported and finalized blocks.
+struct StatusTracker {
+	imported: BTreeMap<u64, TransactionStatus>,
+	finalized: BTreeMap<u64, TransactionStatus>,
+}
+
+impl StatusTracker {
+	fn new() -> Self {
+		Self { imported: BTreeMap::new(), finalized: BTreeMap::new() }
+	}
+
+	/// Record `status` and return `true` if it differs from the last one seen.
+	fn transition(&mut self, transaction_id: u64, status: &TransactionStatus, finalized: bool) -> bool {
+		let seen = if finalized { &mut self.finalized } else { &mut self.imported };
+		if seen.get(&transaction_id) == Some(status) {
+			return false
+		}
+		seen.insert(transaction_id, status.clone());
+		true
+	}
+
+	/// Updates for the `(transaction, pool, status)` records whose status changed since
+	/// they were last seen.
+	fn updates<BlockHash: Clone, BlockNumber: Clone>(
+		&mut self,
+		records: impl IntoIterator<Item = (u64, PoolId, TransactionStatus)>,
+		block_hash: BlockHash,
+		block_number: BlockNumber,
+		finalized: bool,
+	) -> Vec<TransactionStatusUpdate<BlockHash, BlockNumber>> {
+		records
+			.into_iter()
+			.filter(|(transaction_id, _, status)| self.transition(*transaction_id, status, finalized))
+			.map(|(transaction_id, pool_id, status)| TransactionStatusUpdate {
+				transaction_id,
+				pool_id,
+				status,
+				block_hash: block_hash.clone(),
+				block_number: block_number.clone(),
+				finalized,
+			})
+			.collect()
+	}
+}
+
+impl<C, Block> SponsorshipRpc<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block>,
+{
+	/// Version of `SponsorshipApi` exposed by the runtime at `at`.
+	fn api_version<AccountId, Balance, BlockNumber>(&self, at: &BlockId<Block>) -> RpcResult<u32>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+		C::Api: SponsorshipRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+	{
+		self.client
+			.runtime_api()
+			.api_version::<dyn SponsorshipRuntimeApi<Block, AccountId, Balance, BlockNumber>>(at)
+			.map_err(map_api_error)?
+			.ok_or_else(|| rpc_error(Error::UnsupportedApiVersion, "SponsorshipApi is not available"))
+	}
+
+	/// Fail with `UnsupportedApiVersion` unless the runtime provides API v2.
+	fn ensure_v2<AccountId, Balance, BlockNumber>(&self, at: &BlockId<Block>, method: &str) -> RpcResult<()>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+		C::Api: SponsorshipRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+	{
+		let version = self.api_version::<AccountId, Balance, BlockNumber>(at)?;
+		require_api_version(version, API_VERSION_V2, method)
+			.map_err(|e| JsonRpseeError::Call(CallError::Custom(e)))
+	}
+}
+
+impl<C, Block> SponsorshipRpc<C, Block>
+where
+	Block: BlockT,
+	C: BlockchainEvents<Block> + Send + Sync + 'static,
+{
+	/// Stream of `(hash, number, finalized)` for every imported and finalized block.
+	fn block_stream(&self) -> impl futures::Stream<Item = (Block::Hash, NumberFor<Block>, bool)> {
+		let imported = self
+			.client
+			.import_notification_stream()
+			.map(|n| (n.hash, *n.header.number(), false));
+		let finalized = self
+			.client
+			.finality_notification_stream()
+			.map(|n| (n.hash, *n.header.number(), true));
+
+		stream::select(imported, finalized)
+	}
+
+	/// Pipe `updates` into `sink` on the subscription executor, closing the subscription
+	/// with the first error.
+	fn spawn_try_subscription<S, T>(&self, mut sink: SubscriptionSink, updates: S)
+	where
+		S: futures::TryStream<Ok = T, Error = JsonRpseeError> + Send + Unpin + 'static,
+		T: Serialize + Send + 'static,
+	{
+		let fut = async move {
+			sink.pipe_from_try_stream(updates).await;
+		};
+
+		self.executor.spawn("sponsorship-rpc-subscription", Some("rpc"), fut.boxed());
+	}
+}
+
+impl<C, Block, AccountId, Balance, BlockNumber>
+	SponsorshipApiServer<Block::Hash, AccountId, Balance, BlockNumber>
+	for SponsorshipRpc<C, Block>
+where
+	Block: BlockT,
+	AccountId: Clone + std::fmt::Display + Codec + Send + Sync + 'static,
+	Balance: Clone + std::fmt::Display + Codec + Send + Sync + 'static,
+	BlockNumber: Clone + std::fmt::Display + Codec + Serialize + Send + Sync + 'static,
+	NumberFor<Block>: Into<BlockNumber>,
+	C: Send + Sync + 'static,
+	C: ProvideRuntimeApi<Block>,
+	C: HeaderBackend<Block>,
+	C: BlockchainEvents<Block>,
+	C::Api: SponsorshipRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+{
+	fn get_pool(
+		&self,
+		pool_id: PoolId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<PoolInfo<AccountId, Balance, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let runtime_api_result = api.get_pool(&at, pool_id);
+		runtime_api_result.map_err(map_api_error)
+	}
+
+	fn get_pools_by_owner(
+		&self,
+		owner: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(PoolId, PoolInfo<AccountId, Balance, BlockNumber>)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let runtime_api_result = api.get_pools_by_owner(&at, owner);
+		runtime_api_result.map_err(map_api_error)
+	}
+
+	fn get_transaction_history(
+		&self,
+		pool_id: PoolId,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<TransactionRecord<AccountId, Balance, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let runtime_api_result = api.get_transaction_history(&at, pool_id, limit.unwrap_or(50));
+		runtime_api_result.map_err(map_api_error)
+	}
+
+	fn get_user_gas_savings(
+		&self,
+		user: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Balance> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let runtime_api_result = api.get_user_gas_savings(&at, user);
+		runtime_api_result.map_err(map_api_error)
+	}
+
+	fn estimate_gas_cost(
+		&self,
+		target_chain: u32,
+		call_data: Vec<u8>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Balance> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let runtime_api_result = api.estimate_gas_cost(&at, target_chain, call_data);
+		runtime_api_result.map_err(map_api_error)
+	}
+
+	fn estimate_fee_breakdown(
+		&self,
+		target_chain: u32,
+		call_data: Vec<u8>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<FeeBreakdown<Balance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.ensure_v2::<AccountId, Balance, BlockNumber>(&at, "sponsorship_estimateFeeBreakdown")?;
+
+		let runtime_api_result = api.estimate_fee_breakdown(&at, target_chain, call_data);
+		runtime_api_result.map_err(map_api_error)
+	}
+
+	fn get_pool_stats(
+		&self,
+		pool_id: PoolId,
+		from_block: Option<BlockNumber>,
+		to_block: Option<BlockNumber>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<PoolStats<Balance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.ensure_v2::<AccountId, Balance, BlockNumber>(&at, "sponsorship_getPoolStats")?;
+
+		let runtime_api_result = api.get_pool_stats(&at, pool_id, from_block, to_block);
+		runtime_api_result.map_err(map_api_error)
+	}
+
+	fn forecast_runway(
+		&self,
+		pool_id: PoolId,
+		target_runway_days: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<PoolForecast<Balance, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.ensure_v2::<AccountId, Balance, BlockNumber>(&at, "sponsorship_forecastRunway")?;
+
+		let target_runway_days = target_runway_days.unwrap_or(DEFAULT_RUNWAY_DAYS);
+		let runtime_api_result = api.forecast_pool_runway(&at, pool_id, target_runway_days);
+		runtime_api_result.map_err(map_api_error)
+	}
+
+	fn next_sponsorship_window(
+		&self,
+		pool_id: PoolId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<SponsorshipWindow<BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.ensure_v2::<AccountId, Balance, BlockNumber>(&at, "sponsorship_nextSponsorshipWindow")?;
+
+		let runtime_api_result = api.next_sponsorship_window(&at, pool_id);
+		runtime_api_result.map_err(map_api_error)
+	}
+
+	fn dry_run(
+		&self,
+		user: AccountId,
+		pool_id: PoolId,
+		target_chain: u32,
+		call_data: Vec<u8>,
+		credential: Option<Bytes>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<DryRunResult<Balance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.ensure_v2::<AccountId, Balance, BlockNumber>(&at, "sponsorship_dryRun")?;
+
+		let runtime_api_result = api.dry_run_sponsorship(&at, user, pool_id, target_chain, call_data, credential.map(|c| c.to_vec()));
+		runtime_api_result.map_err(map_api_error)
+	}
+
+	fn get_transaction_history_paged(
+		&self,
+		pool_id: PoolId,
+		cursor: Option<u64>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<TransactionHistoryPage<TransactionRecord<AccountId, Balance, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.ensure_v2::<AccountId, Balance, BlockNumber>(&at, "sponsorship_getTransactionHistoryPaged")?;
+
+		let (items, next_cursor) = api
+			.get_transaction_history_paged(&at, pool_id, cursor, limit.unwrap_or(50))
+			.map_err(map_api_error)?;
+		Ok(TransactionHistoryPage { items, next_cursor })
+	}
+
+	fn get_whitelist_count(&self, pool_id: PoolId, at: Option<Block::Hash>) -> RpcResult<u32> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.ensure_v2::<AccountId, Balance, BlockNumber>(&at, "sponsorship_getWhitelistCount")?;
+
+		let runtime_api_result = api.get_whitelist_count(&at, pool_id);
+		runtime_api_result.map_err(map_api_error)
+	}
+
+	fn subscribe_transaction(&self, mut sink: SubscriptionSink, tx_id: u64) -> SubscriptionResult {
+		let best = BlockId::hash(self.client.info().best_hash);
+		if let Err(e) = self.ensure_v2::<AccountId, Balance, BlockNumber>(&best, "sponsorship_subscribeTransaction") {
+			let _ = sink.reject(e);
+			return Ok(())
+		}
+
+		let client = self.client.clone();
+		let mut tracker = StatusTracker::new();
+
+		// A failing lookup closes the subscription with the error rather than skipping the block
+		let updates = self
+			.block_stream()
+			.map(move |(hash, number, finalized)| {
+				let record = client
+					.runtime_api()
+					.get_transaction(&BlockId::hash(hash), tx_id)
+					.map_err(map_api_error)?;
+				let records = record.map(|record| (record.id, record.pool_id, record.status));
+
+				Ok::<_, JsonRpseeError>(stream::iter(
+					tracker.updates(records, hash, number.into(), finalized).into_iter().map(Ok),
+				))
+			})
+			.try_flatten()
+			.boxed();
+
+		self.spawn_try_subscription(sink, updates);
+		Ok(())
+	}
+
+	fn subscribe_pool(&self, mut sink: SubscriptionSink, pool_id: PoolId) -> SubscriptionResult {
+		let best = BlockId::hash(self.client.info().best_hash);
+		if let Err(e) = self.ensure_v2::<AccountId, Balance, BlockNumber>(&best, "sponsorship_subscribePool") {
+			let _ = sink.reject(e);
+			return Ok(())
+		}
+
+		let client = self.client.clone();
+		let mut tracker = StatusTracker::new();
+
+		// Watch the pool's newest transactions through the bounded, ID-ordered page lookup
+		let updates = self
+			.block_stream()
+			.map(move |(hash, number, finalized)| {
+				let (records, _) = client
+					.runtime_api()
+					.get_transaction_history_paged(&BlockId::hash(hash), pool_id, None, POOL_SUBSCRIPTION_WINDOW)
+					.map_err(map_api_error)?;
+				let records = records.into_iter().map(|record| (record.id, record.pool_id, record.status));
+
+				Ok::<_, JsonRpseeError>(stream::iter(
+					tracker.updates(records, hash, number.into(), finalized).into_iter().map(Ok),
+				))
+			})
+			.try_flatten()
+			.boxed();
+
+		self.spawn_try_subscription(sink, updates);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn status_tracker_reports_only_changes() {
+		let mut tracker = StatusTracker::new();
+		let first = tracker.updates(
+			vec![(1, 0, TransactionStatus::Pending), (2, 0, TransactionStatus::Executed)],
+			[1u8; 32],
+			10u32,
+			false,
+		);
+		assert_eq!(first.len(), 2);
+		assert_eq!(first[0].block_number, 10);
+
+		// Unchanged statuses are not reported again; changed ones are.
+		let second = tracker.updates(
+			vec![(1, 0, TransactionStatus::Executed), (2, 0, TransactionStatus::Executed)],
+			[2u8; 32],
+			11u32,
+			false,
+		);
+		assert_eq!(second.len(), 1);
+		assert_eq!(second[0].transaction_id, 1);
+		assert_eq!(second[0].status, TransactionStatus::Executed);
+		assert!(!second[0].finalized);
+	}
+
+	#[test]
+	fn status_tracker_tracks_finalized_separately() {
+		let mut tracker = StatusTracker::new();
+		let records = vec![(7, 3, TransactionStatus::Dispatched)];
+		assert_eq!(tracker.updates(records.clone(), [0u8; 32], 5u32, false).len(), 1);
+
+		// The same status is reported once more when it becomes final.
+		let finalized = tracker.updates(records.clone(), [0u8; 32], 5u32, true);
+		assert_eq!(finalized.len(), 1);
+		assert_eq!(finalized[0].pool_id, 3);
+		assert!(finalized[0].finalized);
+		assert!(tracker.updates(records, [0u8; 32], 5u32, true).is_empty());
+	}
+
+	#[test]
+	fn require_api_version_rejects_older_runtimes() {
+		assert!(require_api_version(2, 2, "sponsorship_getTransaction").is_ok());
+		assert!(require_api_version(3, 2, "sponsorship_getTransaction").is_ok());
+
+		let err = require_api_version(1, 2, "sponsorship_getTransaction").unwrap_err();
+		assert_eq!(err.code(), i32::from(Error::UnsupportedApiVersion));
+	}
+}
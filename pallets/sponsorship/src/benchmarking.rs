@@ -4,9 +4,47 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as Sponsorship;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
+use codec::Encode;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller, BenchmarkError};
 use frame_system::RawOrigin;
-use frame_support::BoundedVec;
+use frame_support::{traits::{Currency, Hooks}, BoundedVec};
+use sp_runtime::traits::{Bounded, Hash, Zero};
+use sp_std::vec::Vec;
+
+/// Give `who` `MaxNftItemsChecked` items of a new collection, all but the last used up
+/// today in pool 0, and a good identity judgement. Returns the collection and registrar.
+fn worst_case_holder<T: Config>(who: &T::AccountId) -> (NftCollectionOf<T>, u32) {
+	let items = T::MaxNftItemsChecked::get();
+	let collection = T::BenchmarkHelper::mint_nfts(who, items);
+	let owned = T::Nfts::owned_items(&collection, who, items);
+	for item in owned.into_iter().take(items.saturating_sub(1) as usize) {
+		NftItemUsage::<T>::insert(0, (collection.clone(), item), (Sponsorship::<T>::current_day(), 1));
+	}
+	(collection, T::BenchmarkHelper::judge_identity(who))
+}
+
+/// `NftHolder` condition that looks through all of the holder's items
+fn limited_nft_holder<T: Config>(collection: &NftCollectionOf<T>) -> RuleNode<T> {
+	RuleNode::NftHolder { collection: collection.clone(), max_per_item_per_day: Some(1) }
+}
+
+/// `All` over `nodes - 1` conditions that a `worst_case_holder` meets: one limited
+/// `NftHolder`, then identity judgements alternating with plain `NftHolder`s
+fn worst_case_rule<T: Config>(
+	nodes: u32,
+	collection: &NftCollectionOf<T>,
+	registrar: u32,
+) -> BoundedVec<RuleNode<T>, T::MaxRuleNodes> {
+	let mut condition = vec![RuleNode::All((nodes - 1) as u8), limited_nft_holder::<T>(collection)];
+	condition.extend((2..nodes).map(|i| {
+		if i % 2 == 0 {
+			RuleNode::IdentityJudgement(BoundedVec::try_from(vec![registrar]).unwrap())
+		} else {
+			RuleNode::NftHolder { collection: collection.clone(), max_per_item_per_day: None }
+		}
+	}));
+	BoundedVec::try_from(condition).unwrap()
+}
 
 benchmarks! {
 	create_pool {
@@ -16,11 +54,19 @@ benchmarks! {
 			daily_spending_limit: 5000u32.into(),
 			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
 			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
 		};
 		let deposit = T::MinPoolDeposit::get();
-	}: _(RawOrigin::Signed(caller), deposit, config)
+		// Caller is one pool short of the limit
+		OwnedPoolCount::<T>::insert(&caller, T::MaxPoolsPerAccount::get() - 1);
+	}: _(RawOrigin::Signed(caller.clone()), deposit, config)
 	verify {
 		assert_eq!(Sponsorship::<T>::next_pool_id(), 1);
+		assert_eq!(Sponsorship::<T>::owned_pool_count(&caller), T::MaxPoolsPerAccount::get());
 	}
 
 	fund_pool {
@@ -30,6 +76,11 @@ benchmarks! {
 			daily_spending_limit: 5000u32.into(),
 			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
 			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
 		};
 		let deposit = T::MinPoolDeposit::get();
 		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
@@ -47,6 +98,11 @@ benchmarks! {
 			daily_spending_limit: 5000u32.into(),
 			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
 			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
 		};
 		let deposit = T::MinPoolDeposit::get();
 		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config.clone());
@@ -55,6 +111,11 @@ benchmarks! {
 			daily_spending_limit: 10000u32.into(),
 			allowed_chains: BoundedVec::try_from(vec![1000u32, 2000u32]).unwrap(),
 			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
 		};
 	}: _(RawOrigin::Signed(caller), 0, new_config.clone())
 	verify {
@@ -69,17 +130,22 @@ benchmarks! {
 			daily_spending_limit: 5000u32.into(),
 			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
 			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
 		};
 		let deposit = T::MinPoolDeposit::get();
 		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
-		let rule = AuthorizationRule {
-			rule_type: AuthorizationType::UserWhitelist,
-			data: BoundedVec::try_from(vec![1u8, 2u8, 3u8]).unwrap(),
-			created_at: 1u32.into(),
-		};
-	}: _(RawOrigin::Signed(caller), 0, rule.clone())
+		let (collection, registrar) = worst_case_holder::<T>(&caller);
+		let condition = worst_case_rule::<T>(T::MaxRuleNodes::get(), &collection, registrar);
+		// Pool is one rule short of the limit
+		PoolRuleCount::<T>::insert(0, T::MaxAuthorizationsPerPool::get() - 1);
+	}: _(RawOrigin::Signed(caller), 0, condition)
 	verify {
-		assert!(Sponsorship::<T>::pool_authorizations(0, &rule.rule_type).is_some());
+		assert!(Sponsorship::<T>::pool_authorizations(0, 0).is_some());
+		assert_eq!(Sponsorship::<T>::pool_rule_count(0), T::MaxAuthorizationsPerPool::get());
 	}
 
 	remove_authorization_rule {
@@ -89,18 +155,20 @@ benchmarks! {
 			daily_spending_limit: 5000u32.into(),
 			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
 			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
 		};
 		let deposit = T::MinPoolDeposit::get();
 		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
-		let rule = AuthorizationRule {
-			rule_type: AuthorizationType::UserWhitelist,
-			data: BoundedVec::try_from(vec![1u8, 2u8, 3u8]).unwrap(),
-			created_at: 1u32.into(),
-		};
-		let _ = Sponsorship::<T>::add_authorization_rule(RawOrigin::Signed(caller.clone()).into(), 0, rule.clone());
-	}: _(RawOrigin::Signed(caller), 0, rule.rule_type.clone())
+		let (collection, registrar) = worst_case_holder::<T>(&caller);
+		let condition = worst_case_rule::<T>(T::MaxRuleNodes::get(), &collection, registrar);
+		let _ = Sponsorship::<T>::add_authorization_rule(RawOrigin::Signed(caller.clone()).into(), 0, condition);
+	}: _(RawOrigin::Signed(caller), 0, 0)
 	verify {
-		assert!(Sponsorship::<T>::pool_authorizations(0, &rule.rule_type).is_none());
+		assert!(Sponsorship::<T>::pool_authorizations(0, 0).is_none());
 	}
 
 	sponsor_transaction {
@@ -110,14 +178,761 @@ benchmarks! {
 			daily_spending_limit: 5000u32.into(),
 			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
 			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
 		};
 		let deposit = T::MinPoolDeposit::get();
 		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
 		let call_data = vec![1u8, 2u8, 3u8, 4u8];
-	}: _(RawOrigin::Signed(caller), 0, 1000u32, call_data)
+	}: _(RawOrigin::Signed(caller), 0, 1000u32, call_data, None)
 	verify {
 		assert_eq!(Sponsorship::<T>::next_transaction_id(), 1);
 	}
 
+	sponsor_transaction_auto {
+		let c in 1 .. T::MaxAutoSelectCandidates::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let deposit = T::MinPoolDeposit::get();
+		// Every pool but the last is too small to cover the fee, forcing a full fallback walk
+		for i in 0 .. c {
+			let config = PoolConfig {
+				max_transaction_value: 1000u32.into(),
+				daily_spending_limit: 5000u32.into(),
+				allowed_chains: BoundedVec::try_from(vec![2006u32]).unwrap(),
+				authorization_required: false,
+				campaign_tag: None,
+				low_balance_threshold: Zero::zero(),
+				expires_at: None,
+				schedule: None,
+				config_update_delay: Zero::zero(),
+			};
+			let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+			if i + 1 < c {
+				Pools::<T>::mutate(i, |pool| {
+					if let Some(pool) = pool {
+						pool.balance = Zero::zero();
+					}
+				});
+			}
+		}
+		let call_data = vec![1u8, 2u8, 3u8, 4u8];
+		// Candidates come from the `ChainPools` index, which also reads one key past the last
+		let candidates = BoundedVec::default();
+	}: _(RawOrigin::Signed(caller), 2006u32, call_data, candidates, PoolSelectionPreference::Any)
+	verify {
+		assert_eq!(Sponsorship::<T>::transaction_log(0).unwrap().pool_id, c - 1);
+	}
+
+	set_auto_top_up {
+		let caller: T::AccountId = whitelisted_caller();
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+		// Trigger above the balance so the pool is also queued
+		let rule = AutoTopUpRule {
+			trigger_balance: deposit + 1u32.into(),
+			amount: 1000u32.into(),
+			daily_cap: 5000u32.into(),
+			lifetime_cap: 50000u32.into(),
+		};
+	}: _(RawOrigin::Signed(caller), 0, rule)
+	verify {
+		assert!(Sponsorship::<T>::auto_top_ups(0).is_some());
+		assert_eq!(Sponsorship::<T>::pending_top_ups().len(), 1);
+	}
+
+	clear_auto_top_up {
+		let caller: T::AccountId = whitelisted_caller();
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+		let rule = AutoTopUpRule {
+			trigger_balance: 1u32.into(),
+			amount: 1000u32.into(),
+			daily_cap: 5000u32.into(),
+			lifetime_cap: 50000u32.into(),
+		};
+		let _ = Sponsorship::<T>::set_auto_top_up(RawOrigin::Signed(caller.clone()).into(), 0, rule);
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert!(Sponsorship::<T>::auto_top_ups(0).is_none());
+	}
+
+	on_initialize_auto_top_up {
+		let n in 1 .. T::MaxPendingTopUps::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, Balance::<T>::max_value() / 2u32.into());
+		let deposit = T::MinPoolDeposit::get();
+		for i in 0 .. n {
+			let config = PoolConfig {
+				max_transaction_value: 1000u32.into(),
+				daily_spending_limit: 5000u32.into(),
+				allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+				authorization_required: false,
+				campaign_tag: None,
+				low_balance_threshold: Zero::zero(),
+				expires_at: None,
+				schedule: None,
+				config_update_delay: Zero::zero(),
+			};
+			let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+			let rule = AutoTopUpRule {
+				trigger_balance: deposit + 1u32.into(),
+				amount: 1000u32.into(),
+				daily_cap: 5000u32.into(),
+				lifetime_cap: 50000u32.into(),
+			};
+			let _ = Sponsorship::<T>::set_auto_top_up(RawOrigin::Signed(caller.clone()).into(), i, rule);
+		}
+		assert_eq!(Sponsorship::<T>::pending_top_ups().len() as u32, n);
+	}: {
+		Sponsorship::<T>::on_initialize(frame_system::Pallet::<T>::block_number());
+	}
+	verify {
+		assert!(Sponsorship::<T>::pending_top_ups().is_empty());
+		assert_eq!(Sponsorship::<T>::pools(n - 1).unwrap().balance, deposit + 1000u32.into());
+	}
+
+	close_pool {
+		let c in 1 .. T::MaxContributorsPerPool::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+		// The owner counts as the first contributor
+		for i in 1 .. c {
+			let contributor: T::AccountId = account("contributor", i, 0);
+			T::Currency::make_free_balance_be(&contributor, deposit + deposit);
+			let _ = Sponsorship::<T>::fund_pool(RawOrigin::Signed(contributor).into(), 0, deposit);
+		}
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert_eq!(Sponsorship::<T>::pools(0).unwrap().status, PoolStatus::Closed);
+	}
+
+	grant_pool_role {
+		let caller: T::AccountId = whitelisted_caller();
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+		let admin: T::AccountId = account("admin", 0, 0);
+	}: _(RawOrigin::Signed(caller), 0, admin.clone(), PoolRole::Admin)
+	verify {
+		assert_eq!(Sponsorship::<T>::pool_roles(0, admin), Some(PoolRole::Admin));
+	}
+
+	revoke_pool_role {
+		let caller: T::AccountId = whitelisted_caller();
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+		let admin: T::AccountId = account("admin", 0, 0);
+		let _ = Sponsorship::<T>::grant_pool_role(RawOrigin::Signed(caller.clone()).into(), 0, admin.clone(), PoolRole::Admin);
+	}: _(RawOrigin::Signed(caller), 0, admin.clone())
+	verify {
+		assert!(Sponsorship::<T>::pool_roles(0, admin).is_none());
+	}
+
+	// Called by an operator so the role lookup is included
+	pause_pool {
+		let caller: T::AccountId = whitelisted_caller();
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+		let operator: T::AccountId = account("operator", 0, 0);
+		let _ = Sponsorship::<T>::grant_pool_role(RawOrigin::Signed(caller).into(), 0, operator.clone(), PoolRole::Operator);
+	}: _(RawOrigin::Signed(operator), 0)
+	verify {
+		assert_eq!(Sponsorship::<T>::pools(0).unwrap().status, PoolStatus::Paused);
+	}
+
+	resume_pool {
+		let caller: T::AccountId = whitelisted_caller();
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+		let operator: T::AccountId = account("operator", 0, 0);
+		let _ = Sponsorship::<T>::grant_pool_role(RawOrigin::Signed(caller.clone()).into(), 0, operator.clone(), PoolRole::Operator);
+		let _ = Sponsorship::<T>::pause_pool(RawOrigin::Signed(caller).into(), 0);
+	}: _(RawOrigin::Signed(operator), 0)
+	verify {
+		assert_eq!(Sponsorship::<T>::pools(0).unwrap().status, PoolStatus::Active);
+	}
+
+	propose_pool_owner {
+		let caller: T::AccountId = whitelisted_caller();
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+	}: _(RawOrigin::Signed(caller), 0, new_owner.clone())
+	verify {
+		assert_eq!(Sponsorship::<T>::pending_pool_owner(0), Some(new_owner));
+	}
+
+	accept_pool_ownership {
+		let caller: T::AccountId = whitelisted_caller();
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		T::Currency::make_free_balance_be(&caller, Balance::<T>::max_value() / 2u32.into());
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		T::Currency::make_free_balance_be(&new_owner, Balance::<T>::max_value() / 2u32.into());
+		// New owner is one pool short of the limit
+		OwnedPoolCount::<T>::insert(&new_owner, T::MaxPoolsPerAccount::get() - 1);
+		let rule = AutoTopUpRule {
+			trigger_balance: 1u32.into(),
+			amount: 1000u32.into(),
+			daily_cap: 5000u32.into(),
+			lifetime_cap: 50000u32.into(),
+		};
+		let _ = Sponsorship::<T>::set_auto_top_up(RawOrigin::Signed(caller.clone()).into(), 0, rule);
+		let _ = Sponsorship::<T>::propose_pool_owner(RawOrigin::Signed(caller).into(), 0, new_owner.clone());
+	}: _(RawOrigin::Signed(new_owner.clone()), 0)
+	verify {
+		assert_eq!(Sponsorship::<T>::pools(0).unwrap().owner, new_owner);
+	}
+
+	cancel_pool_config_update {
+		let caller: T::AccountId = whitelisted_caller();
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 10u32.into(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config.clone());
+		let admin: T::AccountId = account("admin", 0, 0);
+		let _ = Sponsorship::<T>::grant_pool_role(RawOrigin::Signed(caller.clone()).into(), 0, admin.clone(), PoolRole::Admin);
+		let new_config = PoolConfig { max_transaction_value: 2000u32.into(), ..config };
+		let _ = Sponsorship::<T>::update_pool_config(RawOrigin::Signed(caller).into(), 0, new_config);
+	}: _(RawOrigin::Signed(admin), 0)
+	verify {
+		assert!(Sponsorship::<T>::pending_config_update(0).is_none());
+	}
+
+	// Each update also moves the pool's expiry, the costliest change to apply
+	on_initialize_config_updates {
+		let n in 1 .. T::MaxConfigUpdatesPerBlock::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, Balance::<T>::max_value() / 2u32.into());
+		let deposit = T::MinPoolDeposit::get();
+		let now = frame_system::Pallet::<T>::block_number();
+		for i in 0 .. n {
+			let config = PoolConfig {
+				max_transaction_value: 1000u32.into(),
+				daily_spending_limit: 5000u32.into(),
+				allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+				authorization_required: false,
+				campaign_tag: None,
+				low_balance_threshold: Zero::zero(),
+				expires_at: None,
+				schedule: None,
+				config_update_delay: 1u32.into(),
+			};
+			let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config.clone());
+			let new_config = PoolConfig { expires_at: Some(now + 100u32.into()), ..config };
+			let _ = Sponsorship::<T>::update_pool_config(RawOrigin::Signed(caller.clone()).into(), i, new_config);
+		}
+		let apply_at = now + 1u32.into();
+		frame_system::Pallet::<T>::set_block_number(apply_at);
+		assert_eq!(Sponsorship::<T>::config_update_queue(apply_at).len() as u32, n);
+	}: {
+		Sponsorship::<T>::on_initialize(apply_at);
+	}
+	verify {
+		assert!(Sponsorship::<T>::pending_config_update(n - 1).is_none());
+		assert_eq!(Sponsorship::<T>::pools(n - 1).unwrap().config.expires_at, Some(now + 100u32.into()));
+	}
+
+	on_initialize_voucher_nonces {
+		let n in 1 .. T::MaxVoucherExpiriesPerBlock::get();
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		for nonce in 0 .. n {
+			Sponsorship::<T>::record_voucher_nonce(0, nonce.into(), expires_at)?;
+		}
+		let prune_at = expires_at + 1u32.into();
+		frame_system::Pallet::<T>::set_block_number(prune_at);
+	}: {
+		Sponsorship::<T>::on_initialize(prune_at);
+	}
+	verify {
+		assert!(Sponsorship::<T>::used_voucher_nonce(0, u64::from(n - 1)).is_none());
+		assert!(Sponsorship::<T>::voucher_nonce_expiries(prune_at).is_empty());
+	}
+
+	on_initialize_revocations {
+		let n in 1 .. T::MaxRevocationExpiriesPerBlock::get();
+		let attester: T::AccountId = account("attester", 0, 0);
+		let valid_until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		for id in 0 .. n {
+			Sponsorship::<T>::record_revocation(&attester, id.into(), valid_until)?;
+		}
+		let prune_at = valid_until + 1u32.into();
+		frame_system::Pallet::<T>::set_block_number(prune_at);
+	}: {
+		Sponsorship::<T>::on_initialize(prune_at);
+	}
+	verify {
+		assert!(Sponsorship::<T>::revoked_attestation(&attester, u64::from(n - 1)).is_none());
+		assert!(Sponsorship::<T>::revocation_expiries(prune_at).is_empty());
+	}
+
+	// One rule with `n` nodes, plus `r - 1` rules with a single limited `NftHolder`, the
+	// costliest condition
+	evaluate_rules {
+		let r in 1 .. T::MaxAuthorizationsPerPool::get();
+		let n in 2 .. T::MaxRuleNodes::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+		let created_at = frame_system::Pallet::<T>::block_number();
+		let (collection, registrar) = worst_case_holder::<T>(&caller);
+		let condition = worst_case_rule::<T>(n, &collection, registrar);
+		PoolAuthorizations::<T>::insert(0, 0, AuthorizationRule { condition, created_at });
+		for i in 1 .. r {
+			let condition = BoundedVec::try_from(vec![limited_nft_holder::<T>(&collection)]).unwrap();
+			PoolAuthorizations::<T>::insert(0, i, AuthorizationRule { condition, created_at });
+		}
+		let pool_info = Sponsorship::<T>::pools(0).unwrap();
+	}: {
+		Sponsorship::<T>::ensure_authorized(
+			&pool_info,
+			&RuleContext { who: &caller, pool_id: 0, fee: Zero::zero(), call_data: &[], attested_score: None },
+		)
+		.unwrap();
+	}
+
+	add_to_whitelist {
+		let n in 1 .. T::MaxWhitelistBatch::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, Balance::<T>::max_value() / 2u32.into());
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+		let accounts: Vec<T::AccountId> = (0 .. n).map(|i| account("member", i, 0)).collect();
+		let accounts: BoundedVec<_, T::MaxWhitelistBatch> = BoundedVec::try_from(accounts).unwrap();
+	}: _(RawOrigin::Signed(caller), 0, accounts)
+	verify {
+		assert_eq!(Sponsorship::<T>::pool_whitelist_count(0), n);
+	}
+
+	remove_from_whitelist {
+		let n in 1 .. T::MaxWhitelistBatch::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, Balance::<T>::max_value() / 2u32.into());
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+		let accounts: Vec<T::AccountId> = (0 .. n).map(|i| account("member", i, 0)).collect();
+		let accounts: BoundedVec<_, T::MaxWhitelistBatch> = BoundedVec::try_from(accounts).unwrap();
+		let _ = Sponsorship::<T>::add_to_whitelist(RawOrigin::Signed(caller.clone()).into(), 0, accounts.clone());
+	}: _(RawOrigin::Signed(caller), 0, accounts)
+	verify {
+		assert_eq!(Sponsorship::<T>::pool_whitelist_count(0), 0);
+		assert!(Sponsorship::<T>::pool_whitelist_deposit(0).is_zero());
+	}
+
+	set_allowlist_root {
+		let caller: T::AccountId = whitelisted_caller();
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+		let admin: T::AccountId = account("admin", 0, 0);
+		let _ = Sponsorship::<T>::grant_pool_role(RawOrigin::Signed(caller).into(), 0, admin.clone(), PoolRole::Admin);
+		let root = T::Hashing::hash_of(&0u32);
+	}: _(RawOrigin::Signed(admin), 0, Some(root))
+	verify {
+		assert_eq!(Sponsorship::<T>::pool_allowlist_root(0), Some(root));
+	}
+
+	verify_allowlist_proof {
+		let p in 0 .. T::MaxMerkleProofDepth::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let siblings: Vec<T::Hash> = (0 .. p).map(|i| T::Hashing::hash_of(&i)).collect();
+		let proof = AllowlistProof::<T> {
+			quota: Some(u32::MAX),
+			proof: BoundedVec::try_from(siblings).unwrap(),
+		};
+		PoolAllowlistRoots::<T>::insert(0, Sponsorship::<T>::allowlist_root_of(&caller, &proof));
+	}: {
+		Sponsorship::<T>::ensure_allowlisted(&caller, 0, Some(&proof)).unwrap();
+		AllowlistUsage::<T>::mutate(0, &caller, |used| *used = used.saturating_add(1));
+	}
+	verify {
+		assert_eq!(Sponsorship::<T>::allowlist_usage(0, &caller), 1);
+	}
+
+	add_to_pool_deny_list {
+		let caller: T::AccountId = whitelisted_caller();
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+		let admin: T::AccountId = account("admin", 0, 0);
+		let _ = Sponsorship::<T>::grant_pool_role(RawOrigin::Signed(caller).into(), 0, admin.clone(), PoolRole::Admin);
+		let denied: T::AccountId = account("denied", 0, 0);
+	}: _(RawOrigin::Signed(admin), 0, denied.clone())
+	verify {
+		assert!(Sponsorship::<T>::pool_deny_list(0, &denied).is_some());
+	}
+
+	remove_from_pool_deny_list {
+		let caller: T::AccountId = whitelisted_caller();
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+		let admin: T::AccountId = account("admin", 0, 0);
+		let _ = Sponsorship::<T>::grant_pool_role(RawOrigin::Signed(caller).into(), 0, admin.clone(), PoolRole::Admin);
+		let denied: T::AccountId = account("denied", 0, 0);
+		PoolDenyList::<T>::insert(0, &denied, ());
+	}: _(RawOrigin::Signed(admin), 0, denied.clone())
+	verify {
+		assert!(Sponsorship::<T>::pool_deny_list(0, &denied).is_none());
+	}
+
+	add_to_global_deny_list {
+		let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let denied: T::AccountId = account("denied", 0, 0);
+	}: _<T::RuntimeOrigin>(origin, denied.clone())
+	verify {
+		assert!(Sponsorship::<T>::global_deny_list(&denied).is_some());
+	}
+
+	remove_from_global_deny_list {
+		let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let denied: T::AccountId = account("denied", 0, 0);
+		GlobalDenyList::<T>::insert(&denied, ());
+	}: _<T::RuntimeOrigin>(origin, denied.clone())
+	verify {
+		assert!(Sponsorship::<T>::global_deny_list(&denied).is_none());
+	}
+
+	set_voucher_signer {
+		let caller: T::AccountId = whitelisted_caller();
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+		let admin: T::AccountId = account("admin", 0, 0);
+		let _ = Sponsorship::<T>::grant_pool_role(RawOrigin::Signed(caller).into(), 0, admin.clone(), PoolRole::Admin);
+		let signer: T::AccountId = account("signer", 0, 0);
+	}: _(RawOrigin::Signed(admin), 0, Some(signer.clone()))
+	verify {
+		assert_eq!(Sponsorship::<T>::voucher_signer(0), Some(signer));
+	}
+
+	// Signed by the delegated key, so the owner's signature check fails first
+	verify_voucher {
+		let caller: T::AccountId = whitelisted_caller();
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+		let user: T::AccountId = account("user", 0, 0);
+		let call_data = vec![1u8, 2u8, 3u8, 4u8];
+		let voucher = SponsorshipVoucher::<T> {
+			pool_id: 0,
+			user: user.clone(),
+			target_chain: 1000,
+			call: VoucherCall::CallHash(T::Hashing::hash(&call_data)),
+			max_fee: Balance::<T>::max_value(),
+			expires_at: BlockNumberFor::<T>::max_value(),
+			nonce: 0,
+		};
+		let (signer, signature) = T::BenchmarkHelper::sign(&Sponsorship::<T>::voucher_signing_payload(&voucher));
+		VoucherSigners::<T>::insert(0, signer);
+		let signed = SignedVoucher { voucher, signature };
+		let pool_info = Sponsorship::<T>::pools(0).unwrap();
+	}: {
+		Sponsorship::<T>::ensure_valid_voucher(&user, 0, &pool_info, 1000, &call_data, Zero::zero(), &signed)
+			.unwrap();
+		Sponsorship::<T>::record_voucher_nonce(0, signed.voucher.nonce, signed.voucher.expires_at).unwrap();
+	}
+	verify {
+		assert!(Sponsorship::<T>::used_voucher_nonce(0, 0).is_some());
+	}
+
+	add_pool_attester {
+		let caller: T::AccountId = whitelisted_caller();
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+		let admin: T::AccountId = account("admin", 0, 0);
+		let _ = Sponsorship::<T>::grant_pool_role(RawOrigin::Signed(caller).into(), 0, admin.clone(), PoolRole::Admin);
+		PoolAttesterCount::<T>::insert(0, T::MaxAttestersPerPool::get() - 1);
+		let attester: T::AccountId = account("attester", 0, 0);
+	}: _(RawOrigin::Signed(admin), 0, attester.clone())
+	verify {
+		assert!(Sponsorship::<T>::pool_attester(0, &attester).is_some());
+	}
+
+	remove_pool_attester {
+		let caller: T::AccountId = whitelisted_caller();
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+		let admin: T::AccountId = account("admin", 0, 0);
+		let _ = Sponsorship::<T>::grant_pool_role(RawOrigin::Signed(caller).into(), 0, admin.clone(), PoolRole::Admin);
+		let attester: T::AccountId = account("attester", 0, 0);
+		PoolAttesters::<T>::insert(0, &attester, ());
+		PoolAttesterCount::<T>::insert(0, 1);
+	}: _(RawOrigin::Signed(admin), 0, attester.clone())
+	verify {
+		assert!(Sponsorship::<T>::pool_attester(0, &attester).is_none());
+	}
+
+	rotate_pool_attester {
+		let caller: T::AccountId = whitelisted_caller();
+		let config = PoolConfig {
+			max_transaction_value: 1000u32.into(),
+			daily_spending_limit: 5000u32.into(),
+			allowed_chains: BoundedVec::try_from(vec![1000u32]).unwrap(),
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: Zero::zero(),
+			expires_at: None,
+			schedule: None,
+			config_update_delay: Zero::zero(),
+		};
+		let deposit = T::MinPoolDeposit::get();
+		let _ = Sponsorship::<T>::create_pool(RawOrigin::Signed(caller.clone()).into(), deposit, config);
+		let admin: T::AccountId = account("admin", 0, 0);
+		let _ = Sponsorship::<T>::grant_pool_role(RawOrigin::Signed(caller).into(), 0, admin.clone(), PoolRole::Admin);
+		let old: T::AccountId = account("attester", 0, 0);
+		let new: T::AccountId = account("attester", 1, 0);
+		PoolAttesters::<T>::insert(0, &old, ());
+		PoolAttesterCount::<T>::insert(0, 1);
+	}: _(RawOrigin::Signed(admin), 0, old.clone(), new.clone())
+	verify {
+		assert!(Sponsorship::<T>::pool_attester(0, &old).is_none());
+		assert!(Sponsorship::<T>::pool_attester(0, &new).is_some());
+	}
+
+	revoke_attestation {
+		let attester: T::AccountId = whitelisted_caller();
+		PoolAttesters::<T>::insert(0, &attester, ());
+		let valid_until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+	}: _(RawOrigin::Signed(attester.clone()), 0, 0, valid_until)
+	verify {
+		assert!(Sponsorship::<T>::revoked_attestation(&attester, 0).is_some());
+	}
+
+	verify_attestation {
+		let user: T::AccountId = account("user", 0, 0);
+		let mut attestation = Attestation::<T> {
+			attester: user.clone(),
+			subject: user.clone(),
+			score: u32::MAX,
+			valid_until: BlockNumberFor::<T>::max_value(),
+			id: 0,
+		};
+		let (attester, _) = T::BenchmarkHelper::sign(&[]);
+		attestation.attester = attester.clone();
+		let (_, signature) = T::BenchmarkHelper::sign(&Sponsorship::<T>::attestation_signing_payload(&attestation));
+		PoolAttesters::<T>::insert(0, &attester, ());
+		let signed = SignedAttestation { attestation, signature };
+	}: {
+		assert_eq!(Sponsorship::<T>::verify_attestation(&user, 0, &signed), Ok(u32::MAX));
+	}
+
 	impl_benchmark_test_suite!(Sponsorship, crate::mock::new_test_ext(), crate::mock::Test);
 }
\ No newline at end of file
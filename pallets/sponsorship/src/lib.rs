@@ -17,27 +17,62 @@ mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
+pub mod migrations;
+
 pub mod xcm_gateway;
 pub use xcm_gateway::*;
 
-pub mod rpc;
+pub(crate) const LOG_TARGET: &str = "runtime::sponsorship";
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::traits::{AccountIdConversion, Saturating, Zero, Hash};
-	use frame_support::traits::ReservableCurrency;
+	use sp_runtime::{
+		traits::{
+			AccountIdConversion, Bounded, CheckedDiv, Hash, IdentifyAccount, One, Saturating, Verify,
+			Zero,
+		},
+		SaturatedConversion,
+	};
+	use frame_support::{
+		traits::{fungibles::Inspect as FungiblesInspect, BalanceStatus, Currency, ReservableCurrency},
+		weights::WeightToFee,
+	};
+	use sp_runtime::{PerThing, Permill, Perquintill};
 	use sp_std::vec::Vec;
 
 	pub type PoolId = u32;
+	pub type RuleId = u32;
+
+	const MS_PER_MINUTE: u64 = 60_000;
+	const MINUTES_PER_DAY: u16 = 1440;
+	const MINUTES_PER_WEEK: u64 = 7 * MINUTES_PER_DAY as u64;
+	/// Domain tag prefixed to the bytes a pool owner or voucher signer signs
+	pub const VOUCHER_SIGNING_CONTEXT: &[u8] = b"gasleap/voucher";
+	/// Domain tag prefixed to the bytes an attester signs
+	pub const ATTESTATION_SIGNING_CONTEXT: &[u8] = b"gasleap/attestation";
 	pub type Balance<T> = <<T as Config>::Currency as frame_support::traits::Currency<
 		<T as frame_system::Config>::AccountId,
 	>>::Balance;
 	pub type BlockNumberFor<T> = frame_system::pallet_prelude::BlockNumberFor<T>;
+	pub type AssetIdOf<T> = <<T as Config>::Assets as frame_support::traits::fungibles::Inspect<
+		<T as frame_system::Config>::AccountId,
+	>>::AssetId;
+	pub type AssetBalanceOf<T> = <<T as Config>::Assets as frame_support::traits::fungibles::Inspect<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+	pub type NftCollectionOf<T> =
+		<<T as Config>::Nfts as InspectNftHolder<<T as frame_system::Config>::AccountId>>::CollectionId;
+	pub type NftItemOf<T> =
+		<<T as Config>::Nfts as InspectNftHolder<<T as frame_system::Config>::AccountId>>::ItemId;
+
+	/// The in-code storage version
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
@@ -54,18 +89,131 @@ pub mod pallet {
 		#[pallet::constant]
 		type PalletId: Get<frame_support::PalletId>;
 
-		/// Maximum number of pools per account
+		/// Maximum number of open pools per account
 		#[pallet::constant]
 		type MaxPoolsPerAccount: Get<u32>;
 
-		/// Maximum number of authorizations per pool
+		/// Maximum number of authorizations per pool. Sponsoring is charged for evaluating
+		/// all of them, so keep it small enough for `MaxRuleNodes` nodes each to fit a block.
 		#[pallet::constant]
 		type MaxAuthorizationsPerPool: Get<u32>;
 
+		/// Maximum number of nodes in a single authorization rule
+		#[pallet::constant]
+		type MaxRuleNodes: Get<u32>;
+
+		/// Maximum nesting depth of `All`/`Any`/`Not` in an authorization rule
+		#[pallet::constant]
+		type MaxRuleDepth: Get<u32>;
+
 		/// Minimum deposit required to create a pool
 		#[pallet::constant]
 		type MinPoolDeposit: Get<Balance<Self>>;
 
+		/// Deposit reserved from the pool owner for each whitelisted account
+		#[pallet::constant]
+		type WhitelistDepositPerAccount: Get<Balance<Self>>;
+
+		/// Maximum number of accounts added to or removed from a whitelist in one call
+		#[pallet::constant]
+		type MaxWhitelistBatch: Get<u32>;
+
+		/// Maximum number of sibling hashes in an allowlist Merkle proof
+		#[pallet::constant]
+		type MaxMerkleProofDepth: Get<u32>;
+
+		/// Maximum number of attester keys a pool trusts
+		#[pallet::constant]
+		type MaxAttestersPerPool: Get<u32>;
+
+		/// Maximum number of candidate pools passed to automatic pool selection
+		#[pallet::constant]
+		type MaxAutoSelectCandidates: Get<u32>;
+
+		/// Converts the estimated execution weight on the target chain into a fee
+		type WeightToFee: WeightToFee<Balance = Balance<Self>>;
+
+		/// Flat fee for delivering an XCM message to the target chain
+		#[pallet::constant]
+		type XcmDeliveryFee: Get<Balance<Self>>;
+
+		/// Protocol fee, as a share of the execution and delivery cost
+		#[pallet::constant]
+		type ProtocolFee: Get<Permill>;
+
+		/// Safety margin added on top of the estimate, as a share of the execution and delivery cost
+		#[pallet::constant]
+		type FeeSafetyMargin: Get<Permill>;
+
+		/// Number of blocks in a day, used to bucket pool analytics and daily caps
+		#[pallet::constant]
+		type BlocksPerDay: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of pools waiting for an automatic top-up at once
+		#[pallet::constant]
+		type MaxPendingTopUps: Get<u32>;
+
+		/// Maximum number of distinct accounts funding a single pool
+		#[pallet::constant]
+		type MaxContributorsPerPool: Get<u32>;
+
+		/// Maximum number of pools scheduled to expire in the same block
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
+
+		/// Maximum number of redeemed vouchers expiring in the same block. Their nonces are
+		/// pruned once they expire.
+		#[pallet::constant]
+		type MaxVoucherExpiriesPerBlock: Get<u32>;
+
+		/// Maximum number of revoked attestations expiring in the same block. Revocations are
+		/// pruned once the attestation expires.
+		#[pallet::constant]
+		type MaxRevocationExpiriesPerBlock: Get<u32>;
+
+		/// Maximum number of timelocked config updates applied in the same block
+		#[pallet::constant]
+		type MaxConfigUpdatesPerBlock: Get<u32>;
+
+		/// Origin allowed to manage the global deny list
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin allowed to report transaction receipts. Settling a receipt refunds the pool
+		/// and can complete a pending close, so it must only come from a trusted relayer or
+		/// the target chain.
+		type ReceiptOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Signature carried by sponsorship vouchers
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// Public key that voucher signatures are checked against
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// Signs vouchers and sets up NFT holdings and identity judgements for benchmarks
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<
+			Self::AccountId,
+			Self::OffchainSignature,
+			<Self::Nfts as InspectNftHolder<Self::AccountId>>::CollectionId,
+		>;
+
+		/// Fungible assets checked by `MinAssetBalance` rules
+		type Assets: frame_support::traits::fungibles::Inspect<Self::AccountId>;
+
+		/// NFT holdings checked by `NftHolder` rules
+		type Nfts: InspectNftHolder<Self::AccountId>;
+
+		/// Maximum number of a holder's items an `NftHolder` rule with a per-item limit
+		/// looks through for one with uses left today
+		#[pallet::constant]
+		type MaxNftItemsChecked: Get<u32>;
+
+		/// Identity judgements checked by `IdentityJudgement` rules
+		type Identity: InspectIdentity<Self::AccountId>;
+
+		/// Wall-clock time used by timestamp and weekly sponsorship windows
+		type UnixTime: frame_support::traits::UnixTime;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -93,19 +241,162 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
-	/// Pool authorization rules
+	/// Number of open pools owned by each account, bounded by `MaxPoolsPerAccount`
+	#[pallet::storage]
+	#[pallet::getter(fn owned_pool_count)]
+	pub type OwnedPoolCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Open pools by each target chain they allow, for automatic pool selection
+	#[pallet::storage]
+	#[pallet::getter(fn chain_pools)]
+	pub type ChainPools<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u32, Twox64Concat, PoolId, (), OptionQuery>;
+
+	/// Pool authorization rules, keyed by a per-pool rule id
 	#[pallet::storage]
 	#[pallet::getter(fn pool_authorizations)]
 	pub type PoolAuthorizations<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		PoolId,
-		Blake2_128Concat,
-		AuthorizationType,
+		Twox64Concat,
+		RuleId,
 		AuthorizationRule<T>,
 		OptionQuery,
 	>;
 
+	/// Id assigned to the next rule added to each pool
+	#[pallet::storage]
+	#[pallet::getter(fn next_rule_id)]
+	pub type NextRuleId<T: Config> = StorageMap<_, Blake2_128Concat, PoolId, RuleId, ValueQuery>;
+
+	/// Number of rules each pool holds, bounded by `MaxAuthorizationsPerPool`
+	#[pallet::storage]
+	#[pallet::getter(fn pool_rule_count)]
+	pub type PoolRuleCount<T: Config> = StorageMap<_, Blake2_128Concat, PoolId, u32, ValueQuery>;
+
+	/// Accounts whitelisted per pool, with the deposit held for each entry
+	#[pallet::storage]
+	#[pallet::getter(fn pool_whitelist)]
+	pub type PoolWhitelist<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		PoolId,
+		Blake2_128Concat,
+		T::AccountId,
+		Balance<T>,
+		OptionQuery,
+	>;
+
+	/// Number of accounts on each pool's whitelist
+	#[pallet::storage]
+	#[pallet::getter(fn pool_whitelist_count)]
+	pub type PoolWhitelistCount<T: Config> = StorageMap<_, Blake2_128Concat, PoolId, u32, ValueQuery>;
+
+	/// Total whitelist deposit reserved from each pool's owner
+	#[pallet::storage]
+	#[pallet::getter(fn pool_whitelist_deposit)]
+	pub type PoolWhitelistDeposit<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolId, Balance<T>, ValueQuery>;
+
+	/// Merkle root of each pool's allowlist, see `AllowlistProof`
+	#[pallet::storage]
+	#[pallet::getter(fn pool_allowlist_root)]
+	pub type PoolAllowlistRoots<T: Config> = StorageMap<_, Blake2_128Concat, PoolId, T::Hash, OptionQuery>;
+
+	/// Transactions sponsored per allowlisted account, counted against its leaf's quota.
+	/// Kept across root rotations.
+	#[pallet::storage]
+	#[pallet::getter(fn allowlist_usage)]
+	pub type AllowlistUsage<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, PoolId, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Accounts a pool refuses to sponsor, checked before its allow rules
+	#[pallet::storage]
+	#[pallet::getter(fn pool_deny_list)]
+	pub type PoolDenyList<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, PoolId, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// Accounts no pool may sponsor
+	#[pallet::storage]
+	#[pallet::getter(fn global_deny_list)]
+	pub type GlobalDenyList<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// Key a pool owner delegated voucher signing to
+	#[pallet::storage]
+	#[pallet::getter(fn voucher_signer)]
+	pub type VoucherSigners<T: Config> = StorageMap<_, Blake2_128Concat, PoolId, T::AccountId, OptionQuery>;
+
+	/// Attester keys each pool trusts
+	#[pallet::storage]
+	#[pallet::getter(fn pool_attester)]
+	pub type PoolAttesters<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, PoolId, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// Number of attester keys each pool trusts, bounded by `MaxAttestersPerPool`
+	#[pallet::storage]
+	#[pallet::getter(fn pool_attester_count)]
+	pub type PoolAttesterCount<T: Config> = StorageMap<_, Blake2_128Concat, PoolId, u32, ValueQuery>;
+
+	/// Attestation ids each attester has revoked, kept until the attestation expires
+	#[pallet::storage]
+	#[pallet::getter(fn revoked_attestation)]
+	pub type RevokedAttestations<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, u64, (), OptionQuery>;
+
+	/// Voucher nonces already redeemed per pool, kept until the voucher expires
+	#[pallet::storage]
+	#[pallet::getter(fn used_voucher_nonce)]
+	pub type UsedVoucherNonces<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, PoolId, Twox64Concat, u64, (), OptionQuery>;
+
+	/// Redeemed voucher nonces to prune, keyed by the first block their voucher is expired in
+	#[pallet::storage]
+	#[pallet::getter(fn voucher_nonce_expiries)]
+	pub type VoucherNonceExpiries<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(PoolId, u64), T::MaxVoucherExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Revoked attestations to prune, keyed by the first block the attestation is expired in
+	#[pallet::storage]
+	#[pallet::getter(fn revocation_expiries)]
+	pub type RevocationExpiries<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(T::AccountId, u64), T::MaxRevocationExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Block each account was created in, recorded through the `OnNewAccount` hook and removed
+	/// through `OnKilledAccount`, so a reaped and recreated account starts over. Accounts
+	/// created before the hooks were wired up have no entry. The hooks run inside whatever
+	/// created or reaped the account, so runtimes must cover their write in the base
+	/// extrinsic weight.
+	#[pallet::storage]
+	#[pallet::getter(fn account_first_seen)]
+	pub type AccountFirstSeen<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Day and number of transactions each NFT item was used for per pool, for `NftHolder`
+	/// rules with a per-item limit
+	#[pallet::storage]
+	#[pallet::getter(fn nft_item_usage)]
+	pub type NftItemUsage<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		PoolId,
+		Blake2_128Concat,
+		(NftCollectionOf<T>, NftItemOf<T>),
+		(u32, u32),
+		ValueQuery,
+	>;
+
 	/// User spending tracking
 	#[pallet::storage]
 	#[pallet::getter(fn user_spending)]
@@ -129,6 +420,112 @@ pub mod pallet {
 	#[pallet::getter(fn next_transaction_id)]
 	pub type NextTransactionId<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+	/// Pools that have emitted `PoolBalanceLow` and not yet recovered above the threshold
+	#[pallet::storage]
+	#[pallet::getter(fn low_balance_alerted)]
+	pub type LowBalanceAlerted<T: Config> = StorageMap<_, Blake2_128Concat, PoolId, bool, ValueQuery>;
+
+	/// Amount each account has put into a pool, including the owner's deposit
+	#[pallet::storage]
+	#[pallet::getter(fn pool_contributors)]
+	pub type PoolContributors<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		PoolId,
+		Blake2_128Concat,
+		T::AccountId,
+		Balance<T>,
+		ValueQuery,
+	>;
+
+	/// Total contributed to a pool and the number of contributors
+	#[pallet::storage]
+	#[pallet::getter(fn pool_contributions)]
+	pub type PoolContributions<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolId, ContributionTotals<T>, ValueQuery>;
+
+	/// Sponsored transactions per pool that have not settled yet
+	#[pallet::storage]
+	#[pallet::getter(fn pool_in_flight)]
+	pub type PoolInFlight<T: Config> = StorageMap<_, Blake2_128Concat, PoolId, u32, ValueQuery>;
+
+	/// Pools their owner closed while transactions were in flight. They stay paused and
+	/// close when their last transaction settles, so refunds reach the contributors.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_closure)]
+	pub type PendingClosures<T: Config> = StorageMap<_, Blake2_128Concat, PoolId, (), OptionQuery>;
+
+	/// Pools scheduled to expire, keyed by their `expires_at` block
+	#[pallet::storage]
+	#[pallet::getter(fn expiry_queue)]
+	pub type ExpiryQueue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<PoolId, T::MaxExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Timelocked config updates waiting to be applied, at most one per pool
+	#[pallet::storage]
+	#[pallet::getter(fn pending_config_update)]
+	pub type PendingConfigUpdates<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolId, PendingConfigUpdate<T>, OptionQuery>;
+
+	/// Pools with a config update due, keyed by the block it applies in
+	#[pallet::storage]
+	#[pallet::getter(fn config_update_queue)]
+	pub type ConfigUpdateQueue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<PoolId, T::MaxConfigUpdatesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Roles granted by pool owners to other accounts
+	#[pallet::storage]
+	#[pallet::getter(fn pool_roles)]
+	pub type PoolRoles<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		PoolId,
+		Blake2_128Concat,
+		T::AccountId,
+		PoolRole,
+		OptionQuery,
+	>;
+
+	/// Account each pool's owner has proposed as the next owner
+	#[pallet::storage]
+	#[pallet::getter(fn pending_pool_owner)]
+	pub type PendingPoolOwners<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolId, T::AccountId, OptionQuery>;
+
+	/// Automatic top-up rules and their usage, per pool
+	#[pallet::storage]
+	#[pallet::getter(fn auto_top_ups)]
+	pub type AutoTopUps<T: Config> = StorageMap<_, Blake2_128Concat, PoolId, AutoTopUpState<T>>;
+
+	/// Pools that dropped below their top-up trigger, processed at the start of the next block
+	#[pallet::storage]
+	#[pallet::getter(fn pending_top_ups)]
+	pub type PendingTopUps<T: Config> =
+		StorageValue<_, BoundedVec<PoolId, T::MaxPendingTopUps>, ValueQuery>;
+
+	/// Rolling daily analytics per pool, keyed by day index
+	#[pallet::storage]
+	#[pallet::getter(fn pool_daily_stats)]
+	pub type PoolDailyStats<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		PoolId,
+		Twox64Concat,
+		u32,
+		PoolDailySnapshot<Balance<T>>,
+		ValueQuery,
+	>;
+
 	/// Pool configuration and metadata
 	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	#[scale_info(skip_type_params(T))]
@@ -146,9 +543,63 @@ pub mod pallet {
 	#[scale_info(skip_type_params(T))]
 	pub struct PoolConfig<T: Config> {
 		pub max_transaction_value: Balance<T>,
+		/// Most the pool reserves for fees in one day, counting estimates before refunds
 		pub daily_spending_limit: Balance<T>,
 		pub allowed_chains: BoundedVec<u32, ConstU32<100>>, // ParaId list - simplified for demo
 		pub authorization_required: bool,
+		pub campaign_tag: Option<BoundedVec<u8, ConstU32<32>>>,
+		/// Balance below which `PoolBalanceLow` is emitted; zero disables the alert
+		pub low_balance_threshold: Balance<T>,
+		/// Block from which the pool stops sponsoring and is closed once in-flight transactions settle
+		pub expires_at: Option<BlockNumberFor<T>>,
+		/// Windows during which the pool sponsors; `None` sponsors at any time
+		pub schedule: Option<SponsorshipSchedule<T>>,
+		/// Blocks an `update_pool_config` call waits before it is applied, during which it
+		/// can be cancelled; zero applies updates immediately. Only this config is delayed:
+		/// rules, allowlist root, deny lists, attesters, the voucher signer and vouchers
+		/// still take effect at once.
+		pub config_update_delay: BlockNumberFor<T>,
+	}
+
+	/// Config update waiting out the pool's `config_update_delay`
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct PendingConfigUpdate<T: Config> {
+		pub config: PoolConfig<T>,
+		pub apply_at: BlockNumberFor<T>,
+	}
+
+	/// Weekly recurring window, in UTC minutes from the start of the day
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub struct WeeklyWindow {
+		/// Day of the week, 0 is Monday
+		pub day: u8,
+		pub start_minute: u16,
+		/// Exclusive, at most 1440
+		pub end_minute: u16,
+	}
+
+	/// When a pool may sponsor. Each non-empty list must contain the current moment;
+	/// empty lists do not restrict. All ranges are start-inclusive and end-exclusive.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct SponsorshipSchedule<T: Config> {
+		pub block_ranges: BoundedVec<(BlockNumberFor<T>, BlockNumberFor<T>), ConstU32<8>>,
+		/// Unix time ranges in milliseconds
+		pub time_ranges: BoundedVec<(u64, u64), ConstU32<8>>,
+		pub weekly: BoundedVec<WeeklyWindow, ConstU32<14>>,
+	}
+
+	/// Whether a pool's schedule is open, and when it opens next if not
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub enum SponsorshipWindow<BlockNumber> {
+		Open,
+		/// Opens once both the block and the timestamp conditions that are set are reached
+		OpensAt { block: Option<BlockNumber>, timestamp: Option<u64> },
+		/// No window remains
+		Closed,
 	}
 
 	/// Pool status enumeration
@@ -159,23 +610,297 @@ pub mod pallet {
 		Closed,
 	}
 
-	/// Authorization rule types
+	/// Role an account holds on a pool. The owner implicitly holds every permission.
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub enum PoolRole {
+		/// Changes config and authorization rules, and everything an operator can do
+		Admin,
+		/// Pauses and resumes the pool and manages its auto top-up
+		Operator,
+		/// Read-only access for off-chain tooling; grants no extrinsic permissions
+		Auditor,
+	}
+
+	/// Management action checked against a caller's role
+	#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+	pub enum PoolPermission {
+		ManageConfig,
+		ManageRules,
+		Operate,
+	}
+
+	impl PoolRole {
+		/// Whether this role may perform `permission`
+		pub fn allows(&self, permission: PoolPermission) -> bool {
+			match self {
+				PoolRole::Admin => true,
+				PoolRole::Operator => permission == PoolPermission::Operate,
+				PoolRole::Auditor => false,
+			}
+		}
+	}
+
+	/// Preference used when the pallet picks a pool on the user's behalf
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub enum PoolSelectionPreference<T: Config> {
+		/// First eligible pool by pool ID
+		Any,
+		/// Only pools owned by the given account
+		Owner(T::AccountId),
+		/// Only pools whose config carries the given campaign tag
+		CampaignTag(BoundedVec<u8, ConstU32<32>>),
+		/// Pool with the smallest balance that still covers the fee
+		CheapestCoverage,
+	}
+
+	/// One node of an authorization rule. Rules are stored in prefix order: a combinator
+	/// applies to the complete conditions that follow it, e.g.
+	/// `[Any(2), AllowAccounts(..), Not, MaxPerTx(..)]`.
 	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-	pub enum AuthorizationType {
-		UserWhitelist,
-		TransactionType,
-		SpendingLimit,
+	#[scale_info(skip_type_params(T))]
+	pub enum RuleNode<T: Config> {
+		/// Caller is one of these accounts
+		AllowAccounts(BoundedVec<T::AccountId, ConstU32<32>>),
+		/// Caller is none of these accounts
+		DenyAccounts(BoundedVec<T::AccountId, ConstU32<32>>),
+		/// Estimated fee is at most this amount
+		MaxPerTx(Balance<T>),
+		/// Fees sponsored for the caller by this pool today, including this one, stay within
+		/// this amount
+		MaxPerDay(Balance<T>),
+		/// Call data starts with one of these `(pallet index, call index)` pairs
+		CallIndex(BoundedVec<(u8, u8), ConstU32<32>>),
+		/// Caller's free balance is at least this amount
+		MinBalance(Balance<T>),
+		/// Caller holds at least `amount` of `asset`
+		MinAssetBalance { asset: AssetIdOf<T>, amount: AssetBalanceOf<T> },
+		/// Caller's account was created at least this many blocks ago. Accounts older than
+		/// `AccountFirstSeen` tracking always pass, accounts that do not exist never do.
+		MinAccountAge(BlockNumberFor<T>),
+		/// Caller's account nonce is at least this value
+		MinAccountNonce(u32),
+		/// Caller holds an item of `collection`. With a limit, the item is used for at most
+		/// that many sponsored transactions per day. A rule may limit only one such condition.
+		NftHolder { collection: NftCollectionOf<T>, max_per_item_per_day: Option<u32> },
+		/// Caller's identity is judged `KnownGood` or `Reasonable` by one of these registrars
+		IdentityJudgement(BoundedVec<u32, ConstU32<8>>),
+		/// Current block is in `[start, end)`
+		Window { start: BlockNumberFor<T>, end: BlockNumberFor<T> },
+		/// Caller is on the pool's stored whitelist
+		Whitelisted,
+		/// Caller presented an attestation from one of the pool's attesters with at least
+		/// this score
+		MinAttestedScore(u32),
+		/// All of the next `n` conditions hold
+		All(u8),
+		/// Any of the next `n` conditions holds
+		Any(u8),
+		/// The next condition does not hold
+		Not,
 	}
 
 	/// Authorization rule definition
 	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	#[scale_info(skip_type_params(T))]
 	pub struct AuthorizationRule<T: Config> {
-		pub rule_type: AuthorizationType,
-		pub data: BoundedVec<u8, ConstU32<256>>, // Flexible data storage
+		/// A single condition in prefix order, see `RuleNode`
+		pub condition: BoundedVec<RuleNode<T>, T::MaxRuleNodes>,
 		pub created_at: BlockNumberFor<T>,
 	}
 
+	/// Proof that the caller is in a pool's allowlist. The leaf is the hash of
+	/// `(account, quota)` and each parent is the hash of its two children in ascending
+	/// order, so no leaf index is needed.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct AllowlistProof<T: Config> {
+		/// Maximum number of sponsored transactions, or `None` for no limit
+		pub quota: Option<u32>,
+		/// Sibling hashes from the leaf up to the root
+		pub proof: BoundedVec<T::Hash, T::MaxMerkleProofDepth>,
+	}
+
+	/// Which call a voucher is good for
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub enum VoucherCall<T: Config> {
+		/// Exactly the call data with this hash
+		CallHash(T::Hash),
+		/// Any call data starting with this `(pallet index, call index)` pair
+		CallIndex(u8, u8),
+	}
+
+	/// Off-chain sponsorship decision, signed by the pool owner or its voucher signer
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct SponsorshipVoucher<T: Config> {
+		pub pool_id: PoolId,
+		pub user: T::AccountId,
+		pub target_chain: u32,
+		pub call: VoucherCall<T>,
+		/// Highest estimated fee the voucher covers
+		pub max_fee: Balance<T>,
+		/// Last block the voucher can be redeemed in
+		pub expires_at: BlockNumberFor<T>,
+		/// Unique per pool; each nonce is redeemed at most once
+		pub nonce: u64,
+	}
+
+	/// A voucher with the signature over its `voucher_signing_payload`
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct SignedVoucher<T: Config> {
+		pub voucher: SponsorshipVoucher<T>,
+		pub signature: T::OffchainSignature,
+	}
+
+	/// Proofs of eligibility passed to `sponsor_transaction`. Each part that is given is
+	/// checked on its own.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, DefaultNoBound)]
+	#[scale_info(skip_type_params(T))]
+	pub struct SponsorshipCredential<T: Config> {
+		/// Caller's leaf in the pool's allowlist
+		pub allowlist: Option<AllowlistProof<T>>,
+		/// Signed voucher; replaces the pool's allowlist and authorization rules
+		pub voucher: Option<SignedVoucher<T>>,
+		/// Attestation checked by `MinAttestedScore` rules
+		pub attestation: Option<SignedAttestation<T>>,
+	}
+
+	/// Third-party statement about an account, e.g. a KYC level or a reputation score
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Attestation<T: Config> {
+		pub attester: T::AccountId,
+		pub subject: T::AccountId,
+		pub score: u32,
+		/// Last block the attestation is valid in
+		pub valid_until: BlockNumberFor<T>,
+		/// Chosen by the attester; used to revoke the attestation
+		pub id: u64,
+	}
+
+	/// An attestation with the attester's signature over its `attestation_signing_payload`
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct SignedAttestation<T: Config> {
+		pub attestation: Attestation<T>,
+		pub signature: T::OffchainSignature,
+	}
+
+	/// NFT lookups for `NftHolder` rules, so the pallet does not depend on an NFT pallet
+	pub trait InspectNftHolder<AccountId> {
+		type CollectionId: Member + Parameter + MaxEncodedLen;
+		type ItemId: Member + Parameter + MaxEncodedLen;
+
+		/// Up to `limit` items of `collection` owned by `who`, in the same order on every
+		/// call while `who` keeps them
+		fn owned_items(collection: &Self::CollectionId, who: &AccountId, limit: u32) -> Vec<Self::ItemId>;
+	}
+
+	/// No NFTs, so `NftHolder` rules never hold
+	impl<AccountId> InspectNftHolder<AccountId> for () {
+		type CollectionId = u32;
+		type ItemId = u32;
+
+		fn owned_items(_: &u32, _: &AccountId, _: u32) -> Vec<u32> {
+			Vec::new()
+		}
+	}
+
+	/// `InspectNftHolder` for an NFT pallet that can enumerate an account's items, such as
+	/// pallet-nfts
+	pub struct EnumerableNfts<I>(PhantomData<I>);
+
+	impl<AccountId, I> InspectNftHolder<AccountId> for EnumerableNfts<I>
+	where
+		I: frame_support::traits::tokens::nonfungibles_v2::InspectEnumerable<AccountId>,
+		I::CollectionId: Member + Parameter + MaxEncodedLen,
+		I::ItemId: Member + Parameter + MaxEncodedLen,
+	{
+		type CollectionId = I::CollectionId;
+		type ItemId = I::ItemId;
+
+		fn owned_items(collection: &I::CollectionId, who: &AccountId, limit: u32) -> Vec<I::ItemId> {
+			I::owned_in_collection(collection, who).take(limit as usize).collect()
+		}
+	}
+
+	/// Identity lookups for `IdentityJudgement` rules, so the pallet does not depend on an
+	/// identity pallet
+	pub trait InspectIdentity<AccountId> {
+		/// Whether one of `registrars` judged `who`'s identity `KnownGood` or `Reasonable`
+		fn has_good_judgement(who: &AccountId, registrars: &[u32]) -> bool;
+	}
+
+	/// No identities, so `IdentityJudgement` rules never hold
+	impl<AccountId> InspectIdentity<AccountId> for () {
+		fn has_good_judgement(_: &AccountId, _: &[u32]) -> bool {
+			false
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper<AccountId, Signature, NftCollectionId> {
+		/// Sign `message` with a fixed key belonging to the returned account
+		fn sign(message: &[u8]) -> (AccountId, Signature);
+		/// Give `who` `items` items of a new collection and return the collection
+		fn mint_nfts(who: &AccountId, items: u32) -> NftCollectionId;
+		/// Have a new registrar judge `who`'s identity good and return the registrar's index
+		fn judge_identity(who: &AccountId) -> u32;
+	}
+
+	/// What an authorization rule is evaluated against
+	pub(crate) struct RuleContext<'a, T: Config> {
+		pub who: &'a T::AccountId,
+		pub pool_id: PoolId,
+		pub fee: Balance<T>,
+		pub call_data: &'a [u8],
+		/// Score from a verified attestation, if one was presented
+		pub attested_score: Option<u32>,
+	}
+
+	/// Running totals of a pool's contributions
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct ContributionTotals<T: Config> {
+		pub total: Balance<T>,
+		pub contributors: u32,
+	}
+
+	impl<T: Config> Default for ContributionTotals<T> {
+		fn default() -> Self {
+			Self { total: Zero::zero(), contributors: 0 }
+		}
+	}
+
+	/// Owner-defined rule for refilling a pool from the owner's free balance
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct AutoTopUpRule<T: Config> {
+		/// Top up once the pool balance falls below this
+		pub trigger_balance: Balance<T>,
+		/// Amount moved into the pool per top-up
+		pub amount: Balance<T>,
+		/// Maximum topped up per day
+		pub daily_cap: Balance<T>,
+		/// Maximum topped up over the rule's lifetime
+		pub lifetime_cap: Balance<T>,
+	}
+
+	/// Auto top-up rule together with how much it has already moved
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct AutoTopUpState<T: Config> {
+		pub rule: AutoTopUpRule<T>,
+		/// Day index `topped_up_today` refers to
+		pub day: u32,
+		pub topped_up_today: Balance<T>,
+		pub topped_up_total: Balance<T>,
+	}
+
 	/// User spending information
 	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	#[scale_info(skip_type_params(T))]
@@ -211,6 +936,7 @@ pub mod pallet {
 
 	/// Transaction status enumeration
 	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 	pub enum TransactionStatus {
 		Pending,
 		Dispatched,
@@ -219,7 +945,105 @@ pub mod pallet {
 		Refunded,
 	}
 
-	// Pallets use events to inform users when important changes are made.
+	/// Fee estimate split into its components (runtime API v2)
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub struct FeeBreakdown<Balance> {
+		/// Per-chain base execution fee
+		pub base_fee: Balance,
+		/// Fee for the size of the call data
+		pub size_fee: Balance,
+		/// Fee for the estimated execution weight on the target chain
+		pub weight_fee: Balance,
+		/// Fee for delivering the XCM message
+		pub xcm_delivery_fee: Balance,
+		/// GasLeap protocol fee
+		pub protocol_fee: Balance,
+		/// Buffer against under-estimation
+		pub safety_margin: Balance,
+		/// Sum of all components, as charged to the pool
+		pub total: Balance,
+		/// Whether the target chain is unknown and fallback base fee was used
+		pub used_fallback: bool,
+	}
+
+	/// Settled spend towards a single target chain
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub struct ChainSpend<Balance> {
+		pub target_chain: u32,
+		pub settled_count: u32,
+		pub total_spent: Balance,
+	}
+
+	/// Pool activity within one day, updated as transactions are sponsored and settled
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub struct PoolDailySnapshot<Balance> {
+		pub sponsored_count: u32,
+		pub executed_count: u32,
+		pub failed_count: u32,
+		pub refunded_count: u32,
+		/// Estimated fees reserved for transactions sponsored this day, before refunds.
+		/// Checked against the pool's `daily_spending_limit`.
+		pub reserved: Balance,
+		/// Settled cost charged to the pool
+		pub total_spent: Balance,
+		/// Users whose first charged transaction in this pool settled this day
+		pub new_user_count: u32,
+		pub new_user_spent: Balance,
+		pub returning_user_spent: Balance,
+		pub chains: BoundedVec<ChainSpend<Balance>, ConstU32<100>>,
+	}
+
+	/// Pool statistics aggregated over a range of days (runtime API v2)
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub struct PoolStats<Balance> {
+		pub from_day: u32,
+		pub to_day: u32,
+		pub sponsored_count: u32,
+		pub pending_count: u32,
+		pub executed_count: u32,
+		pub failed_count: u32,
+		pub refunded_count: u32,
+		pub total_spent: Balance,
+		/// Average settled cost of executed and failed transactions
+		pub average_fee: Balance,
+		pub new_user_count: u32,
+		pub new_user_spent: Balance,
+		pub returning_user_spent: Balance,
+		pub by_chain: Vec<ChainSpend<Balance>>,
+		pub by_day: Vec<(u32, PoolDailySnapshot<Balance>)>,
+	}
+
+	/// Outcome of simulating `sponsor_transaction` without committing it (runtime API v2)
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub struct DryRunResult<Balance> {
+		pub estimated_fee: Balance,
+		pub pool_balance_after: Option<Balance>,
+		/// Name of the pallet error that would be returned, if any
+		pub error: Option<Vec<u8>>,
+	}
+
+	/// Projected runway of a pool at its recent spend rate (runtime API v2)
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub struct PoolForecast<Balance, BlockNumber> {
+		pub balance: Balance,
+		/// Average settled spend per day over the sampled days
+		pub daily_burn_rate: Balance,
+		pub sample_days: u32,
+		/// Whole days left at the current burn rate, `None` if the pool is not spending
+		pub runway_days: Option<u32>,
+		/// Block at which the pool is projected to run dry
+		pub depletion_block: Option<BlockNumber>,
+		/// Top-up needed to reach the requested runway
+		pub recommended_top_up: Balance,
+	}
+
+	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/main-docs/build/events-errors/
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -233,6 +1057,7 @@ pub mod pallet {
 		/// Pool funded with additional balance
 		PoolFunded {
 			pool_id: PoolId,
+			contributor: T::AccountId,
 			amount: Balance<T>,
 			new_balance: Balance<T>,
 		},
@@ -241,6 +1066,21 @@ pub mod pallet {
 			pool_id: PoolId,
 			owner: T::AccountId,
 		},
+		/// `PoolConfig` update scheduled behind the pool's `config_update_delay`
+		PoolConfigUpdateScheduled {
+			pool_id: PoolId,
+			by: T::AccountId,
+			apply_at: BlockNumberFor<T>,
+		},
+		/// Delayed `PoolConfig` update applied
+		PoolConfigUpdateApplied {
+			pool_id: PoolId,
+		},
+		/// Delayed `PoolConfig` update cancelled before it applied
+		PoolConfigUpdateCancelled {
+			pool_id: PoolId,
+			by: T::AccountId,
+		},
 		/// Transaction sponsored successfully
 		TransactionSponsored {
 			transaction_id: u64,
@@ -252,12 +1092,157 @@ pub mod pallet {
 		/// Authorization rule added
 		AuthorizationRuleAdded {
 			pool_id: PoolId,
-			rule_type: AuthorizationType,
+			rule_id: RuleId,
 		},
 		/// Authorization rule removed
 		AuthorizationRuleRemoved {
 			pool_id: PoolId,
-			rule_type: AuthorizationType,
+			rule_id: RuleId,
+		},
+		/// Accounts added to a pool's whitelist
+		WhitelistExtended {
+			pool_id: PoolId,
+			added: u32,
+			deposit: Balance<T>,
+		},
+		/// Accounts removed from a pool's whitelist
+		WhitelistReduced {
+			pool_id: PoolId,
+			removed: u32,
+			refunded: Balance<T>,
+		},
+		/// Pool allowlist root set, rotated or removed
+		AllowlistRootSet {
+			pool_id: PoolId,
+			root: Option<T::Hash>,
+		},
+		/// Account added to a pool's deny list
+		PoolDenyListAdded {
+			pool_id: PoolId,
+			who: T::AccountId,
+		},
+		/// Account removed from a pool's deny list
+		PoolDenyListRemoved {
+			pool_id: PoolId,
+			who: T::AccountId,
+		},
+		/// Account added to the global deny list
+		GlobalDenyListAdded {
+			who: T::AccountId,
+		},
+		/// Account removed from the global deny list
+		GlobalDenyListRemoved {
+			who: T::AccountId,
+		},
+		/// Pool's delegated voucher signer set or removed
+		VoucherSignerSet {
+			pool_id: PoolId,
+			signer: Option<T::AccountId>,
+		},
+		/// Voucher redeemed for a sponsored transaction
+		VoucherRedeemed {
+			pool_id: PoolId,
+			nonce: u64,
+			transaction_id: u64,
+		},
+		/// Pool started trusting an attester key
+		PoolAttesterAdded {
+			pool_id: PoolId,
+			attester: T::AccountId,
+		},
+		/// Pool stopped trusting an attester key
+		PoolAttesterRemoved {
+			pool_id: PoolId,
+			attester: T::AccountId,
+		},
+		/// Attester revoked one of its attestations
+		AttestationRevoked {
+			attester: T::AccountId,
+			id: u64,
+		},
+		/// Pool balance dropped below its low-balance threshold
+		PoolBalanceLow {
+			pool_id: PoolId,
+			balance: Balance<T>,
+			threshold: Balance<T>,
+		},
+		/// Pool paused by its owner or an operator
+		PoolPaused {
+			pool_id: PoolId,
+			by: T::AccountId,
+		},
+		/// Pool resumed by its owner or an operator
+		PoolResumed {
+			pool_id: PoolId,
+			by: T::AccountId,
+		},
+		/// Role granted on a pool
+		PoolRoleGranted {
+			pool_id: PoolId,
+			who: T::AccountId,
+			role: PoolRole,
+		},
+		/// Role revoked on a pool
+		PoolRoleRevoked {
+			pool_id: PoolId,
+			who: T::AccountId,
+		},
+		/// Pool owner proposed a new owner, who must accept before the transfer happens
+		PoolOwnershipProposed {
+			pool_id: PoolId,
+			owner: T::AccountId,
+			proposed: T::AccountId,
+		},
+		/// Pool ownership transferred, along with the previous owner's reserved deposit
+		PoolOwnershipTransferred {
+			pool_id: PoolId,
+			old_owner: T::AccountId,
+			new_owner: T::AccountId,
+			deposit: Balance<T>,
+		},
+		/// Pool closed and its remaining balance returned to contributors
+		PoolClosed {
+			pool_id: PoolId,
+			refunded: Balance<T>,
+		},
+		/// Pool reached its expiry block and was closed
+		PoolExpired {
+			pool_id: PoolId,
+			refunded: Balance<T>,
+		},
+		/// Contributor received their share of a closed pool's remaining balance
+		ContributionRefunded {
+			pool_id: PoolId,
+			contributor: T::AccountId,
+			amount: Balance<T>,
+		},
+		/// Auto top-up rule set for a pool
+		AutoTopUpSet {
+			pool_id: PoolId,
+			rule: AutoTopUpRule<T>,
+		},
+		/// Auto top-up rule removed from a pool
+		AutoTopUpCleared {
+			pool_id: PoolId,
+		},
+		/// Pool refilled from its owner's free balance by its auto top-up rule
+		PoolAutoToppedUp {
+			pool_id: PoolId,
+			amount: Balance<T>,
+			new_balance: Balance<T>,
+		},
+		/// Transaction settled from its receipt
+		TransactionSettled {
+			transaction_id: u64,
+			pool_id: PoolId,
+			status: TransactionStatus,
+			actual_cost: Balance<T>,
+			refund: Balance<T>,
+		},
+		/// Pool paused and set to close once its in-flight transactions settle
+		PoolClosureScheduled {
+			pool_id: PoolId,
+			in_flight: u32,
 		},
 	}
 
@@ -272,7 +1257,7 @@ pub mod pallet {
 		UnauthorizedUser,
 		/// Invalid configuration
 		InvalidConfiguration,
-		/// Exceeds spending limit
+		/// Sponsoring would take the pool past its daily spending limit
 		ExceedsSpendingLimit,
 		/// Chain not supported
 		ChainNotSupported,
@@ -288,6 +1273,209 @@ pub mod pallet {
 		TooManyPools,
 		/// Deposit too small
 		DepositTooSmall,
+		/// None of the candidate pools is active and matches the selection preference
+		NoEligiblePool,
+		/// XCM fee estimation failed
+		FeeEstimationFailed,
+		/// XCM message could not be built or failed validation
+		MessageFormatError,
+		/// XCM destination is not supported by the gateway
+		UnsupportedDestination,
+		/// XCM message delivery timed out
+		DeliveryTimeout,
+		/// XCM execution failed on the target chain
+		ExecutionFailed,
+		/// Transaction receipt could not be processed
+		ReceiptProcessingError,
+		/// Transaction receipt failed validation
+		InvalidReceipt,
+		/// Pool could not be updated from the receipt
+		PoolUpdateFailed,
+		/// Transaction not found
+		TransactionNotFound,
+		/// Transaction has already been settled
+		TransactionAlreadySettled,
+		/// Auto top-up rule has a zero amount or trigger, or caps below the amount
+		InvalidAutoTopUpRule,
+		/// Pool has no auto top-up rule
+		AutoTopUpNotFound,
+		/// Pool already has the maximum number of contributors
+		TooManyContributors,
+		/// Pool has passed its expiry block
+		PoolExpired,
+		/// Expiry block is not in the future
+		InvalidExpiry,
+		/// Too many pools already expire in that block
+		TooManyExpiries,
+		/// Schedule has an empty range or an out-of-bounds weekly window
+		InvalidSchedule,
+		/// Pool is outside its sponsorship schedule; see `next_sponsorship_window`
+		OutsideSponsorshipWindow,
+		/// Caller holds a role on the pool that does not allow this action
+		InsufficientPoolRole,
+		/// Account holds no role on the pool
+		PoolRoleNotFound,
+		/// Pool is not paused
+		PoolNotPaused,
+		/// Account already owns the pool
+		AlreadyPoolOwner,
+		/// No ownership transfer is pending for the caller on this pool
+		NotProposedOwner,
+		/// Owner's deposit could not be moved to the new owner
+		DepositTransferFailed,
+		/// Pool has no pending config update
+		NoPendingConfigUpdate,
+		/// Too many config updates already apply in that block
+		TooManyConfigUpdates,
+		/// Pool already holds the maximum number of authorization rules
+		TooManyRules,
+		/// Pool has no rule with that id
+		RuleNotFound,
+		/// Rule is not a single well-formed condition
+		InvalidRule,
+		/// Rule nests combinators deeper than `MaxRuleDepth`
+		RuleTooDeep,
+		/// Owner's whitelist deposit could not be moved to the new owner
+		WhitelistDepositTransferFailed,
+		/// Pool has an allowlist and the proof is missing or does not match its root
+		InvalidAllowlistProof,
+		/// Caller has used up its allowlist quota
+		AllowlistQuotaExceeded,
+		/// Caller is on the pool's deny list
+		AccountDenied,
+		/// Caller is on the global deny list
+		AccountDeniedGlobally,
+		/// Account is already on the deny list
+		AlreadyDenied,
+		/// Account is not on the deny list
+		NotDenied,
+		/// Voucher does not match the transaction or is not signed by the pool owner or
+		/// its voucher signer
+		InvalidVoucher,
+		/// Voucher is past its expiry block
+		VoucherExpired,
+		/// Voucher nonce was already redeemed
+		VoucherNonceUsed,
+		/// Estimated fee is above the voucher's `max_fee`
+		VoucherFeeExceeded,
+		/// Attestation is not about the caller, not from a trusted attester or badly signed
+		InvalidAttestation,
+		/// Attestation is past its validity
+		AttestationExpired,
+		/// Attester revoked this attestation
+		AttestationRevoked,
+		/// Pool already trusts this attester
+		AttesterAlreadyTrusted,
+		/// Pool does not trust this attester
+		AttesterNotTrusted,
+		/// Pool already trusts the maximum number of attesters
+		TooManyAttesters,
+		/// Credential passed to a dry-run could not be decoded
+		InvalidCredential,
+		/// Pool closes once its in-flight transactions settle
+		PoolClosing,
+		/// Too many redeemed vouchers already expire in that block
+		TooManyVoucherExpiries,
+		/// Too many revoked attestations already expire in that block
+		TooManyRevocationExpiries,
+	}
+
+	impl<T> From<XcmGatewayError> for Error<T> {
+		fn from(error: XcmGatewayError) -> Self {
+			match error {
+				XcmGatewayError::MessageFormatError => Error::<T>::MessageFormatError,
+				XcmGatewayError::UnsupportedDestination => Error::<T>::UnsupportedDestination,
+				XcmGatewayError::FeeEstimationFailed => Error::<T>::FeeEstimationFailed,
+				XcmGatewayError::DeliveryTimeout => Error::<T>::DeliveryTimeout,
+				XcmGatewayError::ExecutionFailed => Error::<T>::ExecutionFailed,
+				XcmGatewayError::ReceiptProcessingError => Error::<T>::ReceiptProcessingError,
+			}
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let mut weight = T::DbWeight::get().reads(5);
+
+			// Expired vouchers are rejected before their nonce is looked at
+			let expired_nonces = <VoucherNonceExpiries<T>>::take(n);
+			if !expired_nonces.is_empty() {
+				for (pool_id, nonce) in expired_nonces.iter() {
+					<UsedVoucherNonces<T>>::remove(pool_id, nonce);
+				}
+				weight = weight.saturating_add(T::WeightInfo::on_initialize_voucher_nonces(
+					expired_nonces.len() as u32,
+				));
+			}
+
+			// Likewise expired attestations before their revocation
+			let expired_revocations = <RevocationExpiries<T>>::take(n);
+			if !expired_revocations.is_empty() {
+				for (attester, id) in expired_revocations.iter() {
+					<RevokedAttestations<T>>::remove(attester, id);
+				}
+				weight = weight.saturating_add(T::WeightInfo::on_initialize_revocations(
+					expired_revocations.len() as u32,
+				));
+			}
+
+			let updates = <ConfigUpdateQueue<T>>::take(n);
+			if !updates.is_empty() {
+				for pool_id in updates.iter() {
+					Self::try_apply_config_update(*pool_id, n);
+				}
+				weight = weight
+					.saturating_add(T::WeightInfo::on_initialize_config_updates(updates.len() as u32));
+			}
+
+			let pending = <PendingTopUps<T>>::take();
+			if !pending.is_empty() {
+				for pool_id in pending.iter() {
+					Self::do_auto_top_up(*pool_id);
+				}
+				weight = weight
+					.saturating_add(T::WeightInfo::on_initialize_auto_top_up(pending.len() as u32));
+			}
+
+			let expiring = <ExpiryQueue<T>>::take(n);
+			for pool_id in expiring.iter() {
+				Self::try_expire_pool(*pool_id);
+			}
+			weight.saturating_add(
+				T::WeightInfo::close_pool(T::MaxContributorsPerPool::get())
+					.saturating_mul(expiring.len() as u64),
+			)
+		}
+
+		fn integrity_test() {
+			// Sponsoring charges for evaluating every rule of a pool, so the rule bounds must
+			// keep the worst case within a single extrinsic
+			let block_weights = T::BlockWeights::get();
+			let max_extrinsic = block_weights
+				.get(DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or(block_weights.max_block);
+
+			let sponsor = Self::max_sponsor_transaction_base_weight()
+				.saturating_add(Self::max_rule_evaluation_weight());
+			assert!(
+				sponsor.all_lte(max_extrinsic),
+				"worst-case sponsor_transaction weight {:?} exceeds max_extrinsic {:?}",
+				sponsor,
+				max_extrinsic,
+			);
+
+			let candidates = T::MaxAutoSelectCandidates::get();
+			let auto = T::WeightInfo::sponsor_transaction_auto(candidates)
+				.saturating_add(Self::max_rule_evaluation_weight().saturating_mul(candidates.into()));
+			assert!(
+				auto.all_lte(max_extrinsic),
+				"worst-case sponsor_transaction_auto weight {:?} exceeds max_extrinsic {:?}",
+				auto,
+				max_extrinsic,
+			);
+		}
 	}
 
 	// Dispatchable functions allow users to interact with the pallet and invoke state changes.
@@ -311,10 +1499,20 @@ pub mod pallet {
 				Error::<T>::DepositTooSmall
 			);
 
+			let owned = Self::owned_pool_count(&who);
+			ensure!(owned < T::MaxPoolsPerAccount::get(), Error::<T>::TooManyPools);
+
 			// Get next pool ID
 			let pool_id = Self::next_pool_id();
 			let next_id = pool_id.saturating_add(1);
 
+			if let Some(schedule) = &config.schedule {
+				Self::validate_schedule(schedule)?;
+			}
+			if let Some(expires_at) = config.expires_at {
+				Self::schedule_expiry(pool_id, expires_at)?;
+			}
+
 			// Reserve funds from user
 			T::Currency::reserve(&who, initial_deposit)?;
 
@@ -331,7 +1529,12 @@ pub mod pallet {
 			// Store pool information
 			<Pools<T>>::insert(&pool_id, &pool_info);
 			<PoolOwners<T>>::insert(&who, &pool_id, ());
+			<OwnedPoolCount<T>>::insert(&who, owned.saturating_add(1));
+			for chain in pool_info.config.allowed_chains.iter() {
+				<ChainPools<T>>::insert(chain, pool_id, ());
+			}
 			<NextPoolId<T>>::put(next_id);
+			Self::record_contribution(pool_id, &who, initial_deposit)?;
 
 			// Emit event
 			Self::deposit_event(Event::PoolCreated {
@@ -343,7 +1546,8 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Fund an existing pool
+		/// Fund an existing pool. Any account may contribute; contributions are refunded
+		/// pro rata when the pool closes.
 		#[pallet::call_index(1)]
 		#[pallet::weight(T::WeightInfo::fund_pool())]
 		pub fn fund_pool(
@@ -356,25 +1560,12 @@ pub mod pallet {
 			// Get pool info
 			let mut pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
 
-			// Check if user is owner
-			ensure!(pool_info.owner == who, Error::<T>::NotPoolOwner);
-
-			// Check pool status
-			ensure!(pool_info.status == PoolStatus::Active, Error::<T>::PoolPaused);
-
-			// Reserve additional funds
-			T::Currency::reserve(&who, amount)?;
-
-			// Update pool balance
-			pool_info.balance = pool_info.balance.saturating_add(amount);
-			let new_balance = pool_info.balance;
-
-			// Store updated pool info
-			<Pools<T>>::insert(&pool_id, &pool_info);
+			let new_balance = Self::do_fund_pool(pool_id, &mut pool_info, &who, amount)?;
 
 			// Emit event
 			Self::deposit_event(Event::PoolFunded {
 				pool_id,
+				contributor: who,
 				amount,
 				new_balance,
 			});
@@ -382,7 +1573,10 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Update pool configuration
+		/// Update pool configuration. If the current config has a `config_update_delay`, the
+		/// update is scheduled and applied by the hook once the delay has passed. The delay
+		/// covers the `PoolConfig` only; who the pool sponsors can still change immediately
+		/// through its rules, lists and voucher signer.
 		#[pallet::call_index(2)]
 		#[pallet::weight(T::WeightInfo::update_pool_config())]
 		pub fn update_pool_config(
@@ -395,14 +1589,40 @@ pub mod pallet {
 			// Get pool info
 			let mut pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
 
-			// Check if user is owner
-			ensure!(pool_info.owner == who, Error::<T>::NotPoolOwner);
+			// Check the caller may manage this part of the pool
+			Self::ensure_pool_permission(pool_id, &pool_info, &who, PoolPermission::ManageConfig)?;
 
-			// Update configuration
-			pool_info.config = new_config;
+			if let Some(schedule) = &new_config.schedule {
+				Self::validate_schedule(schedule)?;
+			}
 
-			// Store updated pool info
-			<Pools<T>>::insert(&pool_id, &pool_info);
+			let delay = pool_info.config.config_update_delay;
+			if !delay.is_zero() {
+				let apply_at = <frame_system::Pallet<T>>::block_number().saturating_add(delay);
+				if new_config.expires_at != pool_info.config.expires_at {
+					if let Some(expires_at) = new_config.expires_at {
+						ensure!(expires_at > apply_at, Error::<T>::InvalidExpiry);
+					}
+				}
+
+				<ConfigUpdateQueue<T>>::try_mutate(apply_at, |queue| -> DispatchResult {
+					if !queue.contains(&pool_id) {
+						queue.try_push(pool_id).map_err(|_| Error::<T>::TooManyConfigUpdates)?;
+					}
+					Ok(())
+				})?;
+				// Replaces any update already pending; its queue entry is skipped by the hook
+				<PendingConfigUpdates<T>>::insert(
+					pool_id,
+					PendingConfigUpdate { config: new_config, apply_at },
+				);
+
+				Self::deposit_event(Event::PoolConfigUpdateScheduled { pool_id, by: who, apply_at });
+
+				return Ok(())
+			}
+
+			Self::apply_pool_config(pool_id, &mut pool_info, new_config)?;
 
 			// Emit event
 			Self::deposit_event(Event::PoolConfigUpdated {
@@ -413,184 +1633,1879 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Add authorization rule to pool
-		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::add_authorization_rule())]
-		pub fn add_authorization_rule(
+		/// Cancel a pool's pending timelocked config update
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::cancel_pool_config_update())]
+		pub fn cancel_pool_config_update(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::ensure_pool_permission(pool_id, &pool_info, &who, PoolPermission::ManageConfig)?;
+			ensure!(
+				<PendingConfigUpdates<T>>::take(pool_id).is_some(),
+				Error::<T>::NoPendingConfigUpdate
+			);
+
+			Self::deposit_event(Event::PoolConfigUpdateCancelled { pool_id, by: who });
+
+			Ok(())
+		}
+
+		/// Add accounts to a pool's whitelist, reserving `WhitelistDepositPerAccount` from
+		/// the pool owner for each account not already on it
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::add_to_whitelist(accounts.len() as u32))]
+		pub fn add_to_whitelist(
 			origin: OriginFor<T>,
 			pool_id: PoolId,
-			rule: AuthorizationRule<T>,
+			accounts: BoundedVec<T::AccountId, T::MaxWhitelistBatch>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			// Get pool info
 			let pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(pool_info.status != PoolStatus::Closed, Error::<T>::PoolClosed);
+			Self::ensure_pool_permission(pool_id, &pool_info, &who, PoolPermission::ManageRules)?;
+
+			let per_account = T::WhitelistDepositPerAccount::get();
+			let mut added: u32 = 0;
+			for account in accounts.iter() {
+				if !<PoolWhitelist<T>>::contains_key(pool_id, account) {
+					<PoolWhitelist<T>>::insert(pool_id, account, per_account);
+					added = added.saturating_add(1);
+				}
+			}
 
-			// Check if user is owner
-			ensure!(pool_info.owner == who, Error::<T>::NotPoolOwner);
-
-			// Store authorization rule
-			<PoolAuthorizations<T>>::insert(&pool_id, &rule.rule_type, &rule);
+			let deposit = per_account.saturating_mul(added.into());
+			T::Currency::reserve(&pool_info.owner, deposit)?;
+			<PoolWhitelistCount<T>>::mutate(pool_id, |count| *count = count.saturating_add(added));
+			<PoolWhitelistDeposit<T>>::mutate(pool_id, |held| *held = held.saturating_add(deposit));
 
-			// Emit event
-			Self::deposit_event(Event::AuthorizationRuleAdded {
-				pool_id,
-				rule_type: rule.rule_type,
-			});
+			Self::deposit_event(Event::WhitelistExtended { pool_id, added, deposit });
 
 			Ok(())
 		}
 
-		/// Remove authorization rule from pool
-		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::remove_authorization_rule())]
-		pub fn remove_authorization_rule(
+		/// Remove accounts from a pool's whitelist and return their deposits to the pool
+		/// owner. Also allowed on closed pools so the deposits can be reclaimed.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::remove_from_whitelist(accounts.len() as u32))]
+		pub fn remove_from_whitelist(
 			origin: OriginFor<T>,
 			pool_id: PoolId,
-			rule_type: AuthorizationType,
+			accounts: BoundedVec<T::AccountId, T::MaxWhitelistBatch>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			// Get pool info
 			let pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::ensure_pool_permission(pool_id, &pool_info, &who, PoolPermission::ManageRules)?;
+
+			let mut removed: u32 = 0;
+			let mut refunded: Balance<T> = Zero::zero();
+			for account in accounts.iter() {
+				if let Some(deposit) = <PoolWhitelist<T>>::take(pool_id, account) {
+					removed = removed.saturating_add(1);
+					refunded = refunded.saturating_add(deposit);
+				}
+			}
 
-			// Check if user is owner
-			ensure!(pool_info.owner == who, Error::<T>::NotPoolOwner);
-
-			// Remove authorization rule
-			<PoolAuthorizations<T>>::remove(&pool_id, &rule_type);
+			T::Currency::unreserve(&pool_info.owner, refunded);
+			<PoolWhitelistCount<T>>::mutate(pool_id, |count| *count = count.saturating_sub(removed));
+			<PoolWhitelistDeposit<T>>::mutate(pool_id, |held| *held = held.saturating_sub(refunded));
 
-			// Emit event
-			Self::deposit_event(Event::AuthorizationRuleRemoved {
-				pool_id,
-				rule_type,
-			});
+			Self::deposit_event(Event::WhitelistReduced { pool_id, removed, refunded });
 
 			Ok(())
 		}
 
-		/// Sponsor a cross-chain transaction using XCM Gateway
-		#[pallet::call_index(5)]
-		#[pallet::weight(T::WeightInfo::sponsor_transaction())]
-		pub fn sponsor_transaction(
+		/// Set, rotate or remove a pool's allowlist root. Usage recorded against the
+		/// previous root still counts towards each account's quota.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::set_allowlist_root())]
+		pub fn set_allowlist_root(
 			origin: OriginFor<T>,
 			pool_id: PoolId,
-			target_chain: u32,
-			call_data: Vec<u8>,
+			root: Option<T::Hash>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			// Get pool info
-			let mut pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::ensure_pool_permission(pool_id, &pool_info, &who, PoolPermission::ManageRules)?;
+			ensure!(pool_info.status != PoolStatus::Closed, Error::<T>::PoolClosed);
 
-			// Check pool status
-			ensure!(pool_info.status == PoolStatus::Active, Error::<T>::PoolPaused);
+			<PoolAllowlistRoots<T>>::set(pool_id, root);
 
-			// Check if chain is supported
-			ensure!(
-				pool_info.config.allowed_chains.contains(&target_chain),
-				Error::<T>::ChainNotSupported
-			);
+			Self::deposit_event(Event::AllowlistRootSet { pool_id, root });
 
-			// TODO: Implement authorization checks
-			// TODO: Implement spending limit checks
+			Ok(())
+		}
 
-			// Get next transaction ID
-			let transaction_id = Self::next_transaction_id();
-			let next_tx_id = transaction_id.saturating_add(1);
+		/// Stop a pool from sponsoring an account, whatever its allow rules say
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::add_to_pool_deny_list())]
+		pub fn add_to_pool_deny_list(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			who: T::AccountId,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
 
-			// Estimate gas cost using XCM Gateway
-			let estimated_gas_cost = FeeEstimator::<T>::estimate_fee(target_chain, &call_data)
-				.map_err(|_| Error::<T>::ChainNotSupported)?;
+			let pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::ensure_pool_permission(pool_id, &pool_info, &caller, PoolPermission::ManageRules)?;
+			ensure!(!<PoolDenyList<T>>::contains_key(pool_id, &who), Error::<T>::AlreadyDenied);
 
-			// Check if pool has sufficient funds
-			ensure!(
-				pool_info.balance >= estimated_gas_cost,
-				Error::<T>::InsufficientFunds
-			);
+			<PoolDenyList<T>>::insert(pool_id, &who, ());
 
-			// Create transaction record
-			let call_hash = T::Hashing::hash(&call_data);
-			let transaction_record = TransactionRecord {
-				id: transaction_id,
-				pool_id,
-				user: who.clone(),
-				target_chain,
-				call_hash,
-				gas_cost: estimated_gas_cost,
-				status: TransactionStatus::Pending,
-				timestamp: <frame_system::Pallet<T>>::block_number(),
-			};
+			Self::deposit_event(Event::PoolDenyListAdded { pool_id, who });
 
-			// Store transaction record
-			<TransactionLog<T>>::insert(&transaction_id, &transaction_record);
-			<NextTransactionId<T>>::put(next_tx_id);
+			Ok(())
+		}
 
-			// Reserve gas cost from pool
-			pool_info.balance = pool_info.balance.saturating_sub(estimated_gas_cost);
-			pool_info.total_spent = pool_info.total_spent.saturating_add(estimated_gas_cost);
-			<Pools<T>>::insert(&pool_id, &pool_info);
+		/// Remove an account from a pool's deny list
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::remove_from_pool_deny_list())]
+		pub fn remove_from_pool_deny_list(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			who: T::AccountId,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
 
-			// Send cross-chain transaction via XCM Gateway
-			XcmGateway::<T>::send_cross_chain_transaction(
-				pool_id,
-				who.clone(),
-				target_chain,
-				call_data,
-				transaction_id,
-			).map_err(|_| Error::<T>::ChainNotSupported)?;
+			let pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::ensure_pool_permission(pool_id, &pool_info, &caller, PoolPermission::ManageRules)?;
+			ensure!(<PoolDenyList<T>>::take(pool_id, &who).is_some(), Error::<T>::NotDenied);
 
-			// Emit event
-			Self::deposit_event(Event::TransactionSponsored {
-				transaction_id,
-				pool_id,
-				user: who,
-				target_chain,
-				gas_cost: estimated_gas_cost,
-			});
+			Self::deposit_event(Event::PoolDenyListRemoved { pool_id, who });
 
 			Ok(())
 		}
 
-		/// Process transaction receipt from target parachain
-		#[pallet::call_index(6)]
-		#[pallet::weight(T::WeightInfo::process_receipt())]
-		pub fn process_receipt(
-			origin: OriginFor<T>,
-			receipt: TransactionReceipt<T>,
-		) -> DispatchResult {
-			// In production, this would be called by XCM message handler
-			// For demo, allow signed calls for testing
-			let _who = ensure_signed(origin)?;
+		/// Stop every pool from sponsoring an account
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::add_to_global_deny_list())]
+		pub fn add_to_global_deny_list(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(!<GlobalDenyList<T>>::contains_key(&who), Error::<T>::AlreadyDenied);
 
-			// Process the receipt through XCM Gateway
-			let result = ReceiptProcessor::<T>::process_receipt(receipt)
-				.map_err(|_| Error::<T>::InvalidConfiguration)?;
+			<GlobalDenyList<T>>::insert(&who, ());
 
-			// Log the processing result
-			match result {
-				ReceiptProcessingResult::Processed => {
-					log::info!("Receipt processed successfully");
-				},
-				ReceiptProcessingResult::AlreadyProcessed => {
-					log::warn!("Receipt was already processed");
-				},
-				ReceiptProcessingResult::InvalidReceipt => {
-					return Err(Error::<T>::InvalidConfiguration.into());
-				},
-				ReceiptProcessingResult::PoolUpdateFailed => {
-					return Err(Error::<T>::InsufficientFunds.into());
-				},
-			}
+			Self::deposit_event(Event::GlobalDenyListAdded { who });
+
+			Ok(())
+		}
+
+		/// Remove an account from the global deny list
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::remove_from_global_deny_list())]
+		pub fn remove_from_global_deny_list(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(<GlobalDenyList<T>>::take(&who).is_some(), Error::<T>::NotDenied);
+
+			Self::deposit_event(Event::GlobalDenyListRemoved { who });
+
+			Ok(())
+		}
+
+		/// Delegate voucher signing to another key, or revoke the delegation. The owner can
+		/// always sign vouchers itself.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::set_voucher_signer())]
+		pub fn set_voucher_signer(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			signer: Option<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::ensure_pool_permission(pool_id, &pool_info, &who, PoolPermission::ManageRules)?;
+			ensure!(pool_info.status != PoolStatus::Closed, Error::<T>::PoolClosed);
+
+			<VoucherSigners<T>>::set(pool_id, signer.clone());
+
+			Self::deposit_event(Event::VoucherSignerSet { pool_id, signer });
+
+			Ok(())
+		}
+
+		/// Trust an attester key for this pool's `MinAttestedScore` rules
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::add_pool_attester())]
+		pub fn add_pool_attester(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			attester: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::ensure_pool_permission(pool_id, &pool_info, &who, PoolPermission::ManageRules)?;
+			let count = Self::pool_attester_count(pool_id);
+			ensure!(count < T::MaxAttestersPerPool::get(), Error::<T>::TooManyAttesters);
+			ensure!(
+				!<PoolAttesters<T>>::contains_key(pool_id, &attester),
+				Error::<T>::AttesterAlreadyTrusted
+			);
+
+			<PoolAttesters<T>>::insert(pool_id, &attester, ());
+			<PoolAttesterCount<T>>::insert(pool_id, count.saturating_add(1));
+
+			Self::deposit_event(Event::PoolAttesterAdded { pool_id, attester });
+
+			Ok(())
+		}
+
+		/// Stop trusting an attester key
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::remove_pool_attester())]
+		pub fn remove_pool_attester(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			attester: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::ensure_pool_permission(pool_id, &pool_info, &who, PoolPermission::ManageRules)?;
+			ensure!(
+				<PoolAttesters<T>>::take(pool_id, &attester).is_some(),
+				Error::<T>::AttesterNotTrusted
+			);
+			<PoolAttesterCount<T>>::mutate(pool_id, |count| *count = count.saturating_sub(1));
+
+			Self::deposit_event(Event::PoolAttesterRemoved { pool_id, attester });
+
+			Ok(())
+		}
+
+		/// Replace a trusted attester key with a new one in a single step
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::rotate_pool_attester())]
+		pub fn rotate_pool_attester(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			old: T::AccountId,
+			new: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::ensure_pool_permission(pool_id, &pool_info, &who, PoolPermission::ManageRules)?;
+			ensure!(!<PoolAttesters<T>>::contains_key(pool_id, &new), Error::<T>::AttesterAlreadyTrusted);
+			ensure!(<PoolAttesters<T>>::take(pool_id, &old).is_some(), Error::<T>::AttesterNotTrusted);
+			<PoolAttesters<T>>::insert(pool_id, &new, ());
+
+			Self::deposit_event(Event::PoolAttesterRemoved { pool_id, attester: old });
+			Self::deposit_event(Event::PoolAttesterAdded { pool_id, attester: new });
+
+			Ok(())
+		}
+
+		/// Revoke an attestation the caller issued, in every pool that trusts the caller. Only
+		/// attesters `pool_id` trusts may revoke. The revocation is pruned after `valid_until`,
+		/// which must not be earlier than the attestation's own.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::revoke_attestation())]
+		pub fn revoke_attestation(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			id: u64,
+			valid_until: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let attester = ensure_signed(origin)?;
+
+			ensure!(<PoolAttesters<T>>::contains_key(pool_id, &attester), Error::<T>::AttesterNotTrusted);
+			// Expired attestations are rejected anyway
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() <= valid_until,
+				Error::<T>::AttestationExpired
+			);
+			ensure!(
+				!<RevokedAttestations<T>>::contains_key(&attester, id),
+				Error::<T>::AttestationRevoked
+			);
+			Self::record_revocation(&attester, id, valid_until)?;
+
+			Self::deposit_event(Event::AttestationRevoked { attester, id });
+
+			Ok(())
+		}
+
+		/// Add authorization rule to pool. When the pool requires authorization, every rule
+		/// must hold for a transaction to be sponsored.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::add_authorization_rule())]
+		pub fn add_authorization_rule(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			condition: BoundedVec<RuleNode<T>, T::MaxRuleNodes>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// Get pool info
+			let pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			// Check the caller may manage this part of the pool
+			Self::ensure_pool_permission(pool_id, &pool_info, &who, PoolPermission::ManageRules)?;
+
+			let count = Self::pool_rule_count(pool_id);
+			ensure!(count < T::MaxAuthorizationsPerPool::get(), Error::<T>::TooManyRules);
+			Self::validate_rule(&condition)?;
+
+			// Store authorization rule under a fresh id
+			let rule_id = Self::next_rule_id(pool_id);
+			let rule = AuthorizationRule {
+				condition,
+				created_at: <frame_system::Pallet<T>>::block_number(),
+			};
+			<PoolAuthorizations<T>>::insert(&pool_id, rule_id, &rule);
+			<NextRuleId<T>>::insert(pool_id, rule_id.saturating_add(1));
+			<PoolRuleCount<T>>::insert(pool_id, count.saturating_add(1));
+
+			// Emit event
+			Self::deposit_event(Event::AuthorizationRuleAdded {
+				pool_id,
+				rule_id,
+			});
+
+			Ok(())
+		}
+
+		/// Remove authorization rule from pool
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::remove_authorization_rule())]
+		pub fn remove_authorization_rule(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			rule_id: RuleId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// Get pool info
+			let pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			// Check the caller may manage this part of the pool
+			Self::ensure_pool_permission(pool_id, &pool_info, &who, PoolPermission::ManageRules)?;
+
+			// Remove authorization rule
+			ensure!(
+				<PoolAuthorizations<T>>::take(&pool_id, rule_id).is_some(),
+				Error::<T>::RuleNotFound
+			);
+			<PoolRuleCount<T>>::mutate(pool_id, |count| *count = count.saturating_sub(1));
+
+			// Emit event
+			Self::deposit_event(Event::AuthorizationRuleRemoved {
+				pool_id,
+				rule_id,
+			});
+
+			Ok(())
+		}
+
+		/// Sponsor a cross-chain transaction using XCM Gateway. Pools with an allowlist
+		/// root require a proof of the caller's leaf, unless a voucher is given.
+		#[pallet::call_index(5)]
+		#[pallet::weight(
+			Pallet::<T>::sponsor_transaction_base_weight(credential.as_ref())
+				.saturating_add(Pallet::<T>::max_rule_evaluation_weight())
+		)]
+		pub fn sponsor_transaction(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			target_chain: u32,
+			call_data: Vec<u8>,
+			credential: Option<SponsorshipCredential<T>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let base_weight = Self::sponsor_transaction_base_weight(credential.as_ref());
+			let (_, rules_weight) = Self::do_sponsor_transaction(
+				&who,
+				pool_id,
+				target_chain,
+				call_data,
+				credential.as_ref(),
+			)?;
+
+			// Refund the rule evaluation that did not happen
+			Ok(Some(base_weight.saturating_add(rules_weight)).into())
+		}
+
+		/// Process transaction receipt from target parachain, reported by `ReceiptOrigin`.
+		/// Charged for closing the pool, which the last settlement of a closing or expired
+		/// pool does; refunded otherwise.
+		#[pallet::call_index(6)]
+		#[pallet::weight(
+			T::WeightInfo::process_receipt()
+				.saturating_add(T::WeightInfo::close_pool(T::MaxContributorsPerPool::get()))
+		)]
+		pub fn process_receipt(
+			origin: OriginFor<T>,
+			receipt: TransactionReceipt<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ReceiptOrigin::ensure_origin(origin)?;
+
+			// Process the receipt through XCM Gateway
+			let result = ReceiptProcessor::<T>::process_receipt(receipt.clone())
+				.map_err(Error::<T>::from)?;
+
+			// Log the processing result
+			match result {
+				ReceiptProcessingResult::Processed => {
+					let closed = Self::settle_transaction(&receipt)?;
+					log::info!("Receipt processed successfully");
+					if closed {
+						return Ok(().into())
+					}
+				},
+				ReceiptProcessingResult::AlreadyProcessed => {
+					log::warn!("Receipt was already processed");
+				},
+				ReceiptProcessingResult::InvalidReceipt => {
+					return Err(Error::<T>::InvalidReceipt.into());
+				},
+				ReceiptProcessingResult::PoolUpdateFailed => {
+					return Err(Error::<T>::PoolUpdateFailed.into());
+				},
+			}
+
+			Ok(Some(T::WeightInfo::process_receipt()).into())
+		}
+
+		/// Sponsor a cross-chain transaction from a pool chosen by the pallet. With no
+		/// `candidates`, it picks among the first `MaxAutoSelectCandidates` open pools that
+		/// allow `target_chain`; wallets can narrow that down with a list of their own.
+		///
+		/// No credential is passed on, so pools that only sponsor with a voucher, an
+		/// allowlist proof or an attestation are never selected.
+		#[pallet::call_index(7)]
+		#[pallet::weight({
+			let candidates = Pallet::<T>::auto_select_candidate_count(candidates.len() as u32);
+			T::WeightInfo::sponsor_transaction_auto(candidates).saturating_add(
+				Pallet::<T>::max_rule_evaluation_weight().saturating_mul(candidates.into())
+			)
+		})]
+		pub fn sponsor_transaction_auto(
+			origin: OriginFor<T>,
+			target_chain: u32,
+			call_data: Vec<u8>,
+			candidates: BoundedVec<PoolId, T::MaxAutoSelectCandidates>,
+			preference: PoolSelectionPreference<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let candidates = if candidates.is_empty() {
+				<ChainPools<T>>::iter_key_prefix(target_chain)
+					.take(T::MaxAutoSelectCandidates::get() as usize)
+					.collect()
+			} else {
+				candidates.into_inner()
+			};
+			let candidates = Self::select_candidate_pools(target_chain, &candidates, &preference);
+			ensure!(!candidates.is_empty(), Error::<T>::NoEligiblePool);
+
+			let mut last_error: DispatchError = Error::<T>::InsufficientFunds.into();
+			for pool_id in candidates {
+				// Each attempt runs in its own storage layer so a failed pool leaves no trace
+				let result = frame_support::storage::with_storage_layer(|| {
+					Self::do_sponsor_transaction(&who, pool_id, target_chain, call_data.clone(), None)
+				});
+
+				match result {
+					Ok(_) => return Ok(()),
+					// Fall back to the next candidate when this pool can't cover the fee,
+					// its rules or deny list reject the caller or it needs an allowlist proof
+					Err(e)
+						if e == Error::<T>::InsufficientFunds.into() ||
+							e == Error::<T>::UnauthorizedUser.into() ||
+							e == Error::<T>::AccountDenied.into() ||
+							e == Error::<T>::InvalidAllowlistProof.into() =>
+					{
+						last_error = e;
+						continue
+					},
+					Err(e) => return Err(e),
+				}
+			}
+
+			Err(last_error)
+		}
+
+		/// Set or replace the rule that refills a pool from the owner's free balance
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_auto_top_up())]
+		pub fn set_auto_top_up(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			rule: AutoTopUpRule<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::ensure_pool_permission(pool_id, &pool_info, &who, PoolPermission::Operate)?;
+
+			ensure!(
+				!rule.trigger_balance.is_zero() &&
+					!rule.amount.is_zero() &&
+					rule.daily_cap >= rule.amount &&
+					rule.lifetime_cap >= rule.amount,
+				Error::<T>::InvalidAutoTopUpRule
+			);
+
+			// Replacing a rule keeps what it has already moved, so caps can't be reset by re-setting it
+			let state = match Self::auto_top_ups(pool_id) {
+				Some(state) => AutoTopUpState { rule: rule.clone(), ..state },
+				None => AutoTopUpState {
+					rule: rule.clone(),
+					day: Self::current_day(),
+					topped_up_today: Zero::zero(),
+					topped_up_total: Zero::zero(),
+				},
+			};
+			<AutoTopUps<T>>::insert(pool_id, state);
+			Self::schedule_auto_top_up(pool_id, &pool_info);
+
+			Self::deposit_event(Event::AutoTopUpSet { pool_id, rule });
+
+			Ok(())
+		}
+
+		/// Close a pool and return its remaining balance to contributors pro rata.
+		/// A pool with transactions in flight is paused instead and closes when the last
+		/// of them settles.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::close_pool(T::MaxContributorsPerPool::get()))]
+		pub fn close_pool(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(pool_info.owner == who, Error::<T>::NotPoolOwner);
+			ensure!(pool_info.status != PoolStatus::Closed, Error::<T>::PoolClosed);
+			ensure!(!<PendingClosures<T>>::contains_key(pool_id), Error::<T>::PoolClosing);
+
+			let in_flight = Self::pool_in_flight(pool_id);
+			if !in_flight.is_zero() {
+				pool_info.status = PoolStatus::Paused;
+				<Pools<T>>::insert(pool_id, &pool_info);
+				<PendingClosures<T>>::insert(pool_id, ());
+
+				Self::deposit_event(Event::PoolClosureScheduled { pool_id, in_flight });
+				return Ok(())
+			}
+
+			Self::do_close_pool(pool_id, pool_info);
+
+			Ok(())
+		}
+
+		/// Grant `role` on a pool to `who`, replacing any role they already hold
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::grant_pool_role())]
+		pub fn grant_pool_role(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			who: T::AccountId,
+			role: PoolRole,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			let pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(pool_info.owner == owner, Error::<T>::NotPoolOwner);
+
+			<PoolRoles<T>>::insert(pool_id, &who, role);
+
+			Self::deposit_event(Event::PoolRoleGranted { pool_id, who, role });
+
+			Ok(())
+		}
+
+		/// Revoke the role `who` holds on a pool
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::revoke_pool_role())]
+		pub fn revoke_pool_role(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			who: T::AccountId,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			let pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(pool_info.owner == owner, Error::<T>::NotPoolOwner);
+			ensure!(<PoolRoles<T>>::contains_key(pool_id, &who), Error::<T>::PoolRoleNotFound);
+
+			<PoolRoles<T>>::remove(pool_id, &who);
+
+			Self::deposit_event(Event::PoolRoleRevoked { pool_id, who });
+
+			Ok(())
+		}
+
+		/// Stop a pool from sponsoring until it is resumed
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::pause_pool())]
+		pub fn pause_pool(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::ensure_pool_permission(pool_id, &pool_info, &who, PoolPermission::Operate)?;
+			ensure!(pool_info.status != PoolStatus::Closed, Error::<T>::PoolClosed);
+			ensure!(pool_info.status == PoolStatus::Active, Error::<T>::PoolPaused);
+
+			pool_info.status = PoolStatus::Paused;
+			<Pools<T>>::insert(&pool_id, &pool_info);
+
+			Self::deposit_event(Event::PoolPaused { pool_id, by: who });
+
+			Ok(())
+		}
+
+		/// Let a paused pool sponsor again
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::resume_pool())]
+		pub fn resume_pool(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::ensure_pool_permission(pool_id, &pool_info, &who, PoolPermission::Operate)?;
+			ensure!(pool_info.status == PoolStatus::Paused, Error::<T>::PoolNotPaused);
+			ensure!(!<PendingClosures<T>>::contains_key(pool_id), Error::<T>::PoolClosing);
+
+			pool_info.status = PoolStatus::Active;
+			<Pools<T>>::insert(&pool_id, &pool_info);
+
+			Self::deposit_event(Event::PoolResumed { pool_id, by: who });
+
+			Ok(())
+		}
+
+		/// Propose `new_owner` as the pool's owner. The transfer only happens once they call
+		/// `accept_pool_ownership`; proposing again replaces the pending proposal.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::propose_pool_owner())]
+		pub fn propose_pool_owner(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(pool_info.owner == who, Error::<T>::NotPoolOwner);
+			ensure!(pool_info.status != PoolStatus::Closed, Error::<T>::PoolClosed);
+			ensure!(new_owner != who, Error::<T>::AlreadyPoolOwner);
+
+			<PendingPoolOwners<T>>::insert(pool_id, &new_owner);
+
+			Self::deposit_event(Event::PoolOwnershipProposed { pool_id, owner: who, proposed: new_owner });
+
+			Ok(())
+		}
+
+		/// Accept a pending ownership proposal. The previous owner's reserved pool and
+		/// whitelist deposits move with the pool. Any auto top-up rule is cleared since it
+		/// draws on the owner, and so is the previous owner's delegated voucher signer.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::accept_pool_ownership())]
+		pub fn accept_pool_ownership(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Self::pending_pool_owner(pool_id) == Some(who.clone()), Error::<T>::NotProposedOwner);
+			let mut pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(pool_info.status != PoolStatus::Closed, Error::<T>::PoolClosed);
+			let owned = Self::owned_pool_count(&who);
+			ensure!(owned < T::MaxPoolsPerAccount::get(), Error::<T>::TooManyPools);
+
+			let old_owner = pool_info.owner.clone();
+			let deposit = <PoolContributors<T>>::take(pool_id, &old_owner);
+			if !deposit.is_zero() {
+				let unmoved =
+					T::Currency::repatriate_reserved(&old_owner, &who, deposit, BalanceStatus::Reserved)?;
+				ensure!(unmoved.is_zero(), Error::<T>::DepositTransferFailed);
+
+				if <PoolContributors<T>>::contains_key(pool_id, &who) {
+					<PoolContributions<T>>::mutate(pool_id, |totals| {
+						totals.contributors = totals.contributors.saturating_sub(1);
+					});
+				}
+				<PoolContributors<T>>::mutate(pool_id, &who, |contributed| {
+					*contributed = contributed.saturating_add(deposit);
+				});
+			}
+			let whitelist_deposit = Self::pool_whitelist_deposit(pool_id);
+			if !whitelist_deposit.is_zero() {
+				let unmoved = T::Currency::repatriate_reserved(
+					&old_owner,
+					&who,
+					whitelist_deposit,
+					BalanceStatus::Reserved,
+				)?;
+				ensure!(unmoved.is_zero(), Error::<T>::WhitelistDepositTransferFailed);
+			}
+
+			pool_info.owner = who.clone();
+			<Pools<T>>::insert(pool_id, &pool_info);
+			<PoolOwners<T>>::remove(&old_owner, pool_id);
+			<PoolOwners<T>>::insert(&who, pool_id, ());
+			<OwnedPoolCount<T>>::mutate(&old_owner, |count| *count = count.saturating_sub(1));
+			<OwnedPoolCount<T>>::insert(&who, owned.saturating_add(1));
+			<PendingPoolOwners<T>>::remove(pool_id);
+			<PoolRoles<T>>::remove(pool_id, &who);
+			if <AutoTopUps<T>>::take(pool_id).is_some() {
+				Self::deposit_event(Event::AutoTopUpCleared { pool_id });
+			}
+			if <VoucherSigners<T>>::take(pool_id).is_some() {
+				Self::deposit_event(Event::VoucherSignerSet { pool_id, signer: None });
+			}
+
+			Self::deposit_event(Event::PoolOwnershipTransferred {
+				pool_id,
+				old_owner,
+				new_owner: who,
+				deposit,
+			});
+
+			Ok(())
+		}
+
+		/// Remove a pool's auto top-up rule
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::clear_auto_top_up())]
+		pub fn clear_auto_top_up(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::ensure_pool_permission(pool_id, &pool_info, &who, PoolPermission::Operate)?;
+			ensure!(<AutoTopUps<T>>::contains_key(pool_id), Error::<T>::AutoTopUpNotFound);
+
+			<AutoTopUps<T>>::remove(pool_id);
+
+			Self::deposit_event(Event::AutoTopUpCleared { pool_id });
 
 			Ok(())
 		}
 	}
 
-	impl<T: Config> Pallet<T> {
-		/// Get the account ID of the pallet
-		pub fn account_id() -> T::AccountId {
-			T::PalletId::get().into_account_truncating()
+	impl<T: Config> Pallet<T> {
+		/// Get the account ID of the pallet
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// Sponsor a transaction from the given pool and return its transaction ID
+		pub(crate) fn do_sponsor_transaction(
+			who: &T::AccountId,
+			pool_id: PoolId,
+			target_chain: u32,
+			call_data: Vec<u8>,
+			credential: Option<&SponsorshipCredential<T>>,
+		) -> Result<(u64, Weight), DispatchError> {
+			// Get pool info
+			let mut pool_info = Self::pools(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			// Check pool status
+			ensure!(pool_info.status == PoolStatus::Active, Error::<T>::PoolPaused);
+			ensure!(!Self::is_expired(&pool_info), Error::<T>::PoolExpired);
+			if let Some(schedule) = &pool_info.config.schedule {
+				let window = Self::schedule_window(schedule);
+				if window != SponsorshipWindow::Open {
+					log::debug!("Pool {} is outside its sponsorship window: {:?}", pool_id, window);
+					return Err(Error::<T>::OutsideSponsorshipWindow.into())
+				}
+			}
+
+			// Check if chain is supported
+			ensure!(
+				pool_info.config.allowed_chains.contains(&target_chain),
+				Error::<T>::ChainNotSupported
+			);
+
+			// Deny lists take precedence over any allow rule
+			ensure!(!<GlobalDenyList<T>>::contains_key(who), Error::<T>::AccountDeniedGlobally);
+			ensure!(!<PoolDenyList<T>>::contains_key(pool_id, who), Error::<T>::AccountDenied);
+			let (allowlist_proof, voucher, attestation) = match credential {
+				Some(credential) =>
+					(credential.allowlist.as_ref(), credential.voucher.as_ref(), credential.attestation.as_ref()),
+				None => (None, None, None),
+			};
+			if voucher.is_none() {
+				Self::ensure_allowlisted(who, pool_id, allowlist_proof)?;
+			}
+			let attested_score =
+				attestation.map(|signed| Self::verify_attestation(who, pool_id, signed)).transpose()?;
+
+			// Get next transaction ID
+			let transaction_id = Self::next_transaction_id();
+			let next_tx_id = transaction_id.saturating_add(1);
+
+			// Estimate gas cost using XCM Gateway
+			let estimated_gas_cost = FeeEstimator::<T>::estimate_fee(target_chain, &call_data)
+				.map_err(Error::<T>::from)?;
+
+			// A voucher stands in for the pool's own rules
+			let (rules_weight, nft_uses) = match voucher {
+				Some(voucher) => {
+					Self::ensure_valid_voucher(
+						who,
+						pool_id,
+						&pool_info,
+						target_chain,
+						&call_data,
+						estimated_gas_cost,
+						voucher,
+					)?;
+					(Weight::zero(), Vec::new())
+				},
+				None => Self::ensure_authorized(
+					&pool_info,
+					&RuleContext {
+						who,
+						pool_id,
+						fee: estimated_gas_cost,
+						call_data: &call_data,
+						attested_score,
+					},
+				)?,
+			};
+
+			// Check if pool has sufficient funds
+			ensure!(
+				pool_info.balance >= estimated_gas_cost,
+				Error::<T>::InsufficientFunds
+			);
+			let day = Self::current_day();
+			ensure!(
+				Self::pool_daily_stats(pool_id, day).reserved.saturating_add(estimated_gas_cost) <=
+					pool_info.config.daily_spending_limit,
+				Error::<T>::ExceedsSpendingLimit
+			);
+
+			// Create transaction record
+			let call_hash = T::Hashing::hash(&call_data);
+			let transaction_record = TransactionRecord {
+				id: transaction_id,
+				pool_id,
+				user: who.clone(),
+				target_chain,
+				call_hash,
+				gas_cost: estimated_gas_cost,
+				status: TransactionStatus::Pending,
+				timestamp: <frame_system::Pallet<T>>::block_number(),
+			};
+
+			// Store transaction record
+			<TransactionLog<T>>::insert(&transaction_id, &transaction_record);
+			<NextTransactionId<T>>::put(next_tx_id);
+
+			// Reserve gas cost from pool
+			pool_info.balance = pool_info.balance.saturating_sub(estimated_gas_cost);
+			pool_info.total_spent = pool_info.total_spent.saturating_add(estimated_gas_cost);
+			<Pools<T>>::insert(&pool_id, &pool_info);
+			Self::check_low_balance(pool_id, &pool_info);
+			Self::schedule_auto_top_up(pool_id, &pool_info);
+			<PoolInFlight<T>>::mutate(pool_id, |count| *count = count.saturating_add(1));
+
+			<PoolDailyStats<T>>::mutate(pool_id, day, |snapshot| {
+				snapshot.sponsored_count = snapshot.sponsored_count.saturating_add(1);
+				snapshot.reserved = snapshot.reserved.saturating_add(estimated_gas_cost);
+			});
+			Self::record_daily_spend(pool_id, who, estimated_gas_cost);
+			for (collection, item) in nft_uses {
+				Self::record_nft_item_use(pool_id, collection, item);
+			}
+			if allowlist_proof.is_some() && <PoolAllowlistRoots<T>>::contains_key(pool_id) {
+				<AllowlistUsage<T>>::mutate(pool_id, who, |used| *used = used.saturating_add(1));
+			}
+			if let Some(voucher) = voucher {
+				let nonce = voucher.voucher.nonce;
+				Self::record_voucher_nonce(pool_id, nonce, voucher.voucher.expires_at)?;
+				Self::deposit_event(Event::VoucherRedeemed { pool_id, nonce, transaction_id });
+			}
+
+			// Send cross-chain transaction via XCM Gateway
+			XcmGateway::<T>::send_cross_chain_transaction(
+				pool_id,
+				who.clone(),
+				target_chain,
+				call_data,
+				transaction_id,
+			).map_err(Error::<T>::from)?;
+
+			// Emit event
+			Self::deposit_event(Event::TransactionSponsored {
+				transaction_id,
+				pool_id,
+				user: who.clone(),
+				target_chain,
+				gas_cost: estimated_gas_cost,
+			});
+
+			Ok((transaction_id, rules_weight))
+		}
+
+		/// Weight of `sponsor_transaction` before rule evaluation
+		pub fn sponsor_transaction_base_weight(credential: Option<&SponsorshipCredential<T>>) -> Weight {
+			let mut weight = T::WeightInfo::sponsor_transaction();
+			let Some(credential) = credential else { return weight };
+			if let Some(proof) = &credential.allowlist {
+				weight.saturating_accrue(T::WeightInfo::verify_allowlist_proof(proof.proof.len() as u32));
+			}
+			if credential.voucher.is_some() {
+				weight.saturating_accrue(T::WeightInfo::verify_voucher());
+			}
+			if credential.attestation.is_some() {
+				weight.saturating_accrue(T::WeightInfo::verify_attestation());
+			}
+			weight
+		}
+
+		/// `sponsor_transaction_base_weight` for a credential with every part
+		pub fn max_sponsor_transaction_base_weight() -> Weight {
+			T::WeightInfo::sponsor_transaction()
+				.saturating_add(T::WeightInfo::verify_allowlist_proof(T::MaxMerkleProofDepth::get()))
+				.saturating_add(T::WeightInfo::verify_voucher())
+				.saturating_add(T::WeightInfo::verify_attestation())
+		}
+
+		/// Check that an attestation is about `who`, signed by an attester the pool trusts,
+		/// still valid and not revoked. Returns the attested score.
+		pub(crate) fn verify_attestation(
+			who: &T::AccountId,
+			pool_id: PoolId,
+			signed: &SignedAttestation<T>,
+		) -> Result<u32, DispatchError> {
+			let attestation = &signed.attestation;
+			ensure!(
+				attestation.subject == *who &&
+					<PoolAttesters<T>>::contains_key(pool_id, &attestation.attester),
+				Error::<T>::InvalidAttestation
+			);
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() <= attestation.valid_until,
+				Error::<T>::AttestationExpired
+			);
+			ensure!(
+				!<RevokedAttestations<T>>::contains_key(&attestation.attester, attestation.id),
+				Error::<T>::AttestationRevoked
+			);
+			let message = Self::attestation_signing_payload(attestation);
+			ensure!(
+				signed.signature.verify(&message[..], &attestation.attester),
+				Error::<T>::InvalidAttestation
+			);
+			Ok(attestation.score)
+		}
+
+		/// Check that a voucher covers this transaction and is signed by the pool owner or
+		/// its voucher signer
+		pub(crate) fn ensure_valid_voucher(
+			who: &T::AccountId,
+			pool_id: PoolId,
+			pool_info: &PoolInfo<T>,
+			target_chain: u32,
+			call_data: &[u8],
+			fee: Balance<T>,
+			signed: &SignedVoucher<T>,
+		) -> DispatchResult {
+			let voucher = &signed.voucher;
+			ensure!(
+				voucher.pool_id == pool_id && voucher.user == *who && voucher.target_chain == target_chain,
+				Error::<T>::InvalidVoucher
+			);
+			let call_matches = match &voucher.call {
+				VoucherCall::CallHash(hash) => T::Hashing::hash(call_data) == *hash,
+				VoucherCall::CallIndex(pallet, call) =>
+					matches!(call_data, [p, c, ..] if p == pallet && c == call),
+			};
+			ensure!(call_matches, Error::<T>::InvalidVoucher);
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() <= voucher.expires_at,
+				Error::<T>::VoucherExpired
+			);
+			ensure!(fee <= voucher.max_fee, Error::<T>::VoucherFeeExceeded);
+			ensure!(
+				!<UsedVoucherNonces<T>>::contains_key(pool_id, voucher.nonce),
+				Error::<T>::VoucherNonceUsed
+			);
+
+			let message = Self::voucher_signing_payload(voucher);
+			let signed_by = |account: &T::AccountId| signed.signature.verify(&message[..], account);
+			ensure!(
+				signed_by(&pool_info.owner) || Self::voucher_signer(pool_id).map_or(false, |s| signed_by(&s)),
+				Error::<T>::InvalidVoucher
+			);
+			Ok(())
+		}
+
+		/// Bytes a voucher signature covers: a domain tag and the chain's genesis hash ahead
+		/// of the voucher, which names its pool, so it is only valid for that pool on this chain
+		pub fn voucher_signing_payload(voucher: &SponsorshipVoucher<T>) -> Vec<u8> {
+			(VOUCHER_SIGNING_CONTEXT, Self::genesis_hash(), voucher).encode()
+		}
+
+		/// Bytes an attestation signature covers, tagged apart from vouchers and tied to
+		/// this chain's genesis hash
+		pub fn attestation_signing_payload(attestation: &Attestation<T>) -> Vec<u8> {
+			(ATTESTATION_SIGNING_CONTEXT, Self::genesis_hash(), attestation).encode()
+		}
+
+		fn genesis_hash() -> T::Hash {
+			<frame_system::Pallet<T>>::block_hash(BlockNumberFor::<T>::zero())
+		}
+
+		/// Mark a voucher nonce redeemed until the voucher expires
+		pub(crate) fn record_voucher_nonce(
+			pool_id: PoolId,
+			nonce: u64,
+			expires_at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			<VoucherNonceExpiries<T>>::try_mutate(expires_at.saturating_add(One::one()), |queue| {
+				queue.try_push((pool_id, nonce)).map_err(|_| Error::<T>::TooManyVoucherExpiries)
+			})?;
+			<UsedVoucherNonces<T>>::insert(pool_id, nonce, ());
+			Ok(())
+		}
+
+		/// Mark an attestation revoked until it expires
+		pub(crate) fn record_revocation(
+			attester: &T::AccountId,
+			id: u64,
+			valid_until: BlockNumberFor<T>,
+		) -> DispatchResult {
+			<RevocationExpiries<T>>::try_mutate(valid_until.saturating_add(One::one()), |queue| {
+				queue.try_push((attester.clone(), id)).map_err(|_| Error::<T>::TooManyRevocationExpiries)
+			})?;
+			<RevokedAttestations<T>>::insert(attester, id, ());
+			Ok(())
+		}
+
+		/// Check the caller against the pool's allowlist root and its quota, if the pool
+		/// has one
+		pub(crate) fn ensure_allowlisted(
+			who: &T::AccountId,
+			pool_id: PoolId,
+			allowlist_proof: Option<&AllowlistProof<T>>,
+		) -> DispatchResult {
+			let Some(root) = Self::pool_allowlist_root(pool_id) else { return Ok(()) };
+			let proof = allowlist_proof.ok_or(Error::<T>::InvalidAllowlistProof)?;
+			ensure!(
+				Self::allowlist_root_of(who, proof) == root,
+				Error::<T>::InvalidAllowlistProof
+			);
+			if let Some(quota) = proof.quota {
+				ensure!(Self::allowlist_usage(pool_id, who) < quota, Error::<T>::AllowlistQuotaExceeded);
+			}
+			Ok(())
+		}
+
+		/// Root implied by a proof for `who`'s leaf
+		pub fn allowlist_root_of(who: &T::AccountId, proof: &AllowlistProof<T>) -> T::Hash {
+			proof.proof.iter().fold(T::Hashing::hash_of(&(who, proof.quota)), |node, sibling| {
+				if node <= *sibling {
+					T::Hashing::hash_of(&(node, sibling))
+				} else {
+					T::Hashing::hash_of(&(sibling, node))
+				}
+			})
+		}
+
+		/// Worst-case weight of evaluating one pool's authorization rules
+		pub fn max_rule_evaluation_weight() -> Weight {
+			let max_rules = T::MaxAuthorizationsPerPool::get();
+			T::WeightInfo::evaluate_rules(max_rules, max_rules.saturating_mul(T::MaxRuleNodes::get()))
+				.saturating_add(T::DbWeight::get().writes(max_rules.into()))
+		}
+
+		/// Check that a rule is exactly one condition within `MaxRuleDepth`
+		pub(crate) fn validate_rule(nodes: &[RuleNode<T>]) -> DispatchResult {
+			let mut pos = 0;
+			Self::validate_rule_node(nodes, &mut pos, 1)?;
+			ensure!(pos == nodes.len(), Error::<T>::InvalidRule);
+			ensure!(
+				nodes.iter().filter(|node| Self::limited_nft_collection(node).is_some()).count() <= 1,
+				Error::<T>::InvalidRule
+			);
+			Ok(())
+		}
+
+		fn validate_rule_node(nodes: &[RuleNode<T>], pos: &mut usize, depth: u32) -> DispatchResult {
+			ensure!(depth <= T::MaxRuleDepth::get(), Error::<T>::RuleTooDeep);
+			let node = nodes.get(*pos).ok_or(Error::<T>::InvalidRule)?;
+			*pos += 1;
+
+			match node {
+				RuleNode::All(n) | RuleNode::Any(n) => {
+					ensure!(*n > 0, Error::<T>::InvalidRule);
+					for _ in 0..*n {
+						Self::validate_rule_node(nodes, pos, depth + 1)?;
+					}
+				},
+				RuleNode::Not => Self::validate_rule_node(nodes, pos, depth + 1)?,
+				RuleNode::Window { start, end } => ensure!(start < end, Error::<T>::InvalidRule),
+				_ => {},
+			}
+
+			Ok(())
+		}
+
+		/// Evaluate every rule of a pool that requires authorization. A pool requiring
+		/// authorization without any rules sponsors nobody. Returns the evaluation weight
+		/// and the NFT items whose per-item limit the transaction counts towards.
+		pub(crate) fn ensure_authorized(
+			pool_info: &PoolInfo<T>,
+			ctx: &RuleContext<T>,
+		) -> Result<(Weight, Vec<(NftCollectionOf<T>, NftItemOf<T>)>), DispatchError> {
+			if !pool_info.config.authorization_required {
+				return Ok((Weight::zero(), Vec::new()))
+			}
+
+			let (mut rules, mut nodes) = (0u32, 0u32);
+			let mut authorized = true;
+			let mut nft_uses = Vec::new();
+			for (_, rule) in <PoolAuthorizations<T>>::iter_prefix(ctx.pool_id) {
+				rules = rules.saturating_add(1);
+				nodes = nodes.saturating_add(rule.condition.len() as u32);
+				let mut rule_uses = Vec::new();
+				if !Self::evaluate_rule_node(&rule.condition, &mut 0, ctx, &mut rule_uses) {
+					authorized = false;
+					break
+				}
+				// Rules sharing a collection pick the same item; it is used once
+				for item_use in rule_uses {
+					if !nft_uses.contains(&item_use) {
+						nft_uses.push(item_use);
+					}
+				}
+			}
+
+			ensure!(authorized && rules > 0, Error::<T>::UnauthorizedUser);
+			let weight = T::WeightInfo::evaluate_rules(rules, nodes)
+				.saturating_add(T::DbWeight::get().writes(nft_uses.len() as u64));
+			Ok((weight, nft_uses))
+		}
+
+		/// Collection of an `NftHolder` condition with a per-item limit
+		fn limited_nft_collection(node: &RuleNode<T>) -> Option<NftCollectionOf<T>> {
+			match node {
+				RuleNode::NftHolder { collection, max_per_item_per_day: Some(_) } => Some(collection.clone()),
+				_ => None,
+			}
+		}
+
+		/// Transactions `item` was used for in a pool today
+		fn nft_item_used_today(pool_id: PoolId, collection: &NftCollectionOf<T>, item: NftItemOf<T>) -> u32 {
+			let (day, used) = <NftItemUsage<T>>::get(pool_id, (collection.clone(), item));
+			if day == Self::current_day() {
+				used
+			} else {
+				0
+			}
+		}
+
+		/// Count a sponsored transaction towards `item` of `collection`
+		fn record_nft_item_use(pool_id: PoolId, collection: NftCollectionOf<T>, item: NftItemOf<T>) {
+			let today = Self::current_day();
+			<NftItemUsage<T>>::mutate(pool_id, (collection, item), |(day, used)| {
+				if *day != today {
+					*day = today;
+					*used = 0;
+				}
+				*used = used.saturating_add(1);
+			});
+		}
+
+		/// Blocks since `who` was created, `Bounded::max_value()` for accounts that predate
+		/// `AccountFirstSeen` tracking, or `None` if the account does not exist
+		fn account_age(who: &T::AccountId) -> Option<BlockNumberFor<T>> {
+			if !<frame_system::Pallet<T>>::account_exists(who) {
+				return None
+			}
+			Some(match <AccountFirstSeen<T>>::get(who) {
+				Some(created) => <frame_system::Pallet<T>>::block_number().saturating_sub(created),
+				None => Bounded::max_value(),
+			})
+		}
+
+		/// Evaluate the condition starting at `pos`. Children are always evaluated so `pos`
+		/// ends up past the whole condition. Adds the NFT items that limited `NftHolder`
+		/// conditions deciding the outcome were satisfied with to `uses`.
+		fn evaluate_rule_node(
+			nodes: &[RuleNode<T>],
+			pos: &mut usize,
+			ctx: &RuleContext<T>,
+			uses: &mut Vec<(NftCollectionOf<T>, NftItemOf<T>)>,
+		) -> bool {
+			// Stored rules are validated, so running out of nodes never happens
+			let Some(node) = nodes.get(*pos) else { return false };
+			*pos += 1;
+
+			match node {
+				RuleNode::AllowAccounts(accounts) => accounts.contains(ctx.who),
+				RuleNode::DenyAccounts(accounts) => !accounts.contains(ctx.who),
+				RuleNode::MaxPerTx(max) => ctx.fee <= *max,
+				RuleNode::MaxPerDay(max) =>
+					Self::spent_today(ctx.pool_id, ctx.who).saturating_add(ctx.fee) <= *max,
+				RuleNode::CallIndex(calls) => match ctx.call_data {
+					[pallet, call, ..] => calls.contains(&(*pallet, *call)),
+					_ => false,
+				},
+				RuleNode::MinBalance(min) => T::Currency::free_balance(ctx.who) >= *min,
+				RuleNode::MinAssetBalance { asset, amount } =>
+					T::Assets::balance(asset.clone(), ctx.who) >= *amount,
+				RuleNode::MinAccountAge(age) => Self::account_age(ctx.who).map_or(false, |a| a >= *age),
+				RuleNode::MinAccountNonce(min) =>
+					<frame_system::Pallet<T>>::account_nonce(ctx.who) >= (*min).into(),
+				RuleNode::NftHolder { collection, max_per_item_per_day: None } =>
+					!T::Nfts::owned_items(collection, ctx.who, 1).is_empty(),
+				RuleNode::NftHolder { collection, max_per_item_per_day: Some(max) } => {
+					// Use the first owned item that has uses left today
+					let item = T::Nfts::owned_items(collection, ctx.who, T::MaxNftItemsChecked::get())
+						.into_iter()
+						.find(|item| Self::nft_item_used_today(ctx.pool_id, collection, item.clone()) < *max);
+					match item {
+						Some(item) => {
+							uses.push((collection.clone(), item));
+							true
+						},
+						None => false,
+					}
+				},
+				RuleNode::IdentityJudgement(registrars) => T::Identity::has_good_judgement(ctx.who, registrars),
+				RuleNode::Window { start, end } =>
+					(*start..*end).contains(&<frame_system::Pallet<T>>::block_number()),
+				RuleNode::Whitelisted => <PoolWhitelist<T>>::contains_key(ctx.pool_id, ctx.who),
+				RuleNode::MinAttestedScore(min) => ctx.attested_score.map_or(false, |score| score >= *min),
+				RuleNode::All(n) => {
+					let mut result = true;
+					let mut all_uses = Vec::new();
+					for _ in 0..*n {
+						result &= Self::evaluate_rule_node(nodes, pos, ctx, &mut all_uses);
+					}
+					if result {
+						uses.append(&mut all_uses);
+					}
+					result
+				},
+				RuleNode::Any(n) => {
+					// Of the conditions that hold, the one using the fewest items decides
+					let mut decisive: Option<Vec<_>> = None;
+					for _ in 0..*n {
+						let mut branch_uses = Vec::new();
+						if Self::evaluate_rule_node(nodes, pos, ctx, &mut branch_uses) &&
+							decisive.as_ref().map_or(true, |d| branch_uses.len() < d.len())
+						{
+							decisive = Some(branch_uses);
+						}
+					}
+					match decisive {
+						Some(mut branch_uses) => {
+							uses.append(&mut branch_uses);
+							true
+						},
+						None => false,
+					}
+				},
+				RuleNode::Not => !Self::evaluate_rule_node(nodes, pos, ctx, &mut Vec::new()),
+			}
+		}
+
+		/// Fees sponsored for `who` by a pool so far today
+		pub fn spent_today(pool_id: PoolId, who: &T::AccountId) -> Balance<T> {
+			let spending = Self::user_spending(pool_id, who);
+			if Self::day_of(spending.last_reset) == Self::current_day() {
+				spending.daily_spent
+			} else {
+				Zero::zero()
+			}
+		}
+
+		/// Count an estimated fee towards `who`'s daily spend in a pool. Refunds on
+		/// settlement are not subtracted.
+		fn record_daily_spend(pool_id: PoolId, who: &T::AccountId, fee: Balance<T>) {
+			let now = <frame_system::Pallet<T>>::block_number();
+			<UserSpending<T>>::mutate(pool_id, who, |spending| {
+				if Self::day_of(spending.last_reset) != Self::day_of(now) {
+					spending.daily_spent = Zero::zero();
+					spending.last_reset = now;
+				}
+				spending.daily_spent = spending.daily_spent.saturating_add(fee);
+			});
+		}
+
+
+		/// Settle a sponsored transaction from its receipt, refunding unused fee to the pool.
+		/// Returns whether settling it closed the pool.
+		pub(crate) fn settle_transaction(receipt: &TransactionReceipt<T>) -> Result<bool, DispatchError> {
+			let mut record = Self::transaction_log(receipt.transaction_id)
+				.ok_or(Error::<T>::TransactionNotFound)?;
+			ensure!(record.target_chain == receipt.target_chain, Error::<T>::InvalidReceipt);
+			ensure!(
+				matches!(record.status, TransactionStatus::Pending | TransactionStatus::Dispatched),
+				Error::<T>::TransactionAlreadySettled
+			);
+
+			// Failed executions still pay for the gas they burned; undelivered ones are refunded in full
+			let (status, actual_cost) = match receipt.execution_result {
+				ExecutionResult::Success =>
+					(TransactionStatus::Executed, receipt.actual_gas_used.min(record.gas_cost)),
+				ExecutionResult::Failed(_) =>
+					(TransactionStatus::Failed, receipt.actual_gas_used.min(record.gas_cost)),
+				ExecutionResult::Timeout | ExecutionResult::InsufficientFunds =>
+					(TransactionStatus::Refunded, Zero::zero()),
+			};
+			let refund = record.gas_cost.saturating_sub(actual_cost);
+
+			let pool_info = <Pools<T>>::try_mutate(
+				record.pool_id,
+				|maybe_pool| -> Result<PoolInfo<T>, DispatchError> {
+					let pool_info = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+					pool_info.balance = pool_info.balance.saturating_add(refund);
+					pool_info.total_spent = pool_info.total_spent.saturating_sub(refund);
+					Ok(pool_info.clone())
+				},
+			)?;
+			Self::check_low_balance(record.pool_id, &pool_info);
+			let in_flight = <PoolInFlight<T>>::mutate(record.pool_id, |count| {
+				*count = count.saturating_sub(1);
+				*count
+			});
+
+			Self::record_settlement(&record, &status, actual_cost);
+
+			record.status = status.clone();
+			record.gas_cost = actual_cost;
+			<TransactionLog<T>>::insert(record.id, &record);
+
+			Self::deposit_event(Event::TransactionSettled {
+				transaction_id: record.id,
+				pool_id: record.pool_id,
+				status,
+				actual_cost,
+				refund,
+			});
+
+			// The last in-flight transaction of a closing or expired pool lets it close
+			if in_flight == 0 {
+				if <PendingClosures<T>>::contains_key(record.pool_id) {
+					if let Some(pool_info) = Self::pools(record.pool_id) {
+						Self::do_close_pool(record.pool_id, pool_info);
+						return Ok(true)
+					}
+				} else if Self::is_expired(&pool_info) {
+					return Ok(Self::try_expire_pool(record.pool_id))
+				}
+			}
+
+			Ok(false)
+		}
+
+		/// Reserve `amount` from `contributor` and add it to the pool balance
+		pub(crate) fn do_fund_pool(
+			pool_id: PoolId,
+			pool_info: &mut PoolInfo<T>,
+			contributor: &T::AccountId,
+			amount: Balance<T>,
+		) -> Result<Balance<T>, DispatchError> {
+			// Check pool status
+			ensure!(pool_info.status == PoolStatus::Active, Error::<T>::PoolPaused);
+			ensure!(!Self::is_expired(pool_info), Error::<T>::PoolExpired);
+
+			Self::record_contribution(pool_id, contributor, amount)?;
+
+			// Reserve additional funds
+			T::Currency::reserve(contributor, amount)?;
+
+			// Update pool balance
+			pool_info.balance = pool_info.balance.saturating_add(amount);
+
+			// Store updated pool info
+			<Pools<T>>::insert(&pool_id, &*pool_info);
+			Self::check_low_balance(pool_id, pool_info);
+
+			Ok(pool_info.balance)
+		}
+
+		/// Check that `who` may perform `permission` on a pool.
+		///
+		/// Accounts without any role get `NotPoolOwner`, as before roles existed.
+		pub fn ensure_pool_permission(
+			pool_id: PoolId,
+			pool_info: &PoolInfo<T>,
+			who: &T::AccountId,
+			permission: PoolPermission,
+		) -> DispatchResult {
+			if pool_info.owner == *who {
+				return Ok(())
+			}
+
+			match Self::pool_roles(pool_id, who) {
+				Some(role) if role.allows(permission) => Ok(()),
+				Some(_) => Err(Error::<T>::InsufficientPoolRole.into()),
+				None => Err(Error::<T>::NotPoolOwner.into()),
+			}
+		}
+
+		/// Whether the pool has reached its `expires_at` block
+		pub fn is_expired(pool_info: &PoolInfo<T>) -> bool {
+			pool_info
+				.config
+				.expires_at
+				.map_or(false, |expires_at| <frame_system::Pallet<T>>::block_number() >= expires_at)
+		}
+
+		/// Check that every range in a schedule is non-empty and weekly windows fit in a day
+		pub(crate) fn validate_schedule(schedule: &SponsorshipSchedule<T>) -> DispatchResult {
+			ensure!(
+				schedule.block_ranges.iter().all(|(start, end)| start < end) &&
+					schedule.time_ranges.iter().all(|(start, end)| start < end) &&
+					schedule.weekly.iter().all(|w| {
+						w.day < 7 && w.start_minute < w.end_minute && w.end_minute <= MINUTES_PER_DAY
+					}),
+				Error::<T>::InvalidSchedule
+			);
+			Ok(())
+		}
+
+		/// Sponsorship window of a pool at the current block and time (for RPC)
+		pub fn next_sponsorship_window(pool_id: PoolId) -> Option<SponsorshipWindow<BlockNumberFor<T>>> {
+			let pool_info = Self::pools(pool_id)?;
+			Some(match &pool_info.config.schedule {
+				Some(schedule) => Self::schedule_window(schedule),
+				None => SponsorshipWindow::Open,
+			})
+		}
+
+		/// Evaluate a schedule against the current block and time
+		pub fn schedule_window(schedule: &SponsorshipSchedule<T>) -> SponsorshipWindow<BlockNumberFor<T>> {
+			let now_block = <frame_system::Pallet<T>>::block_number();
+			let now_ms = <T::UnixTime as frame_support::traits::UnixTime>::now()
+				.as_millis()
+				.saturated_into::<u64>();
+
+			let block = if schedule.block_ranges.is_empty() ||
+				schedule.block_ranges.iter().any(|(start, end)| *start <= now_block && now_block < *end)
+			{
+				None
+			} else {
+				match schedule.block_ranges.iter().map(|(start, _)| *start).filter(|s| *s > now_block).min() {
+					Some(start) => Some(start),
+					None => return SponsorshipWindow::Closed,
+				}
+			};
+
+			let timestamp = match Self::next_time_opening(schedule, now_ms) {
+				Some(at) if at == now_ms => None,
+				Some(at) => Some(at),
+				None => return SponsorshipWindow::Closed,
+			};
+
+			if block.is_none() && timestamp.is_none() {
+				SponsorshipWindow::Open
+			} else {
+				SponsorshipWindow::OpensAt { block, timestamp }
+			}
+		}
+
+		/// Earliest time at or after `from` that satisfies both the time ranges and the weekly
+		/// pattern, or `None` if there is none
+		fn next_time_opening(schedule: &SponsorshipSchedule<T>, from: u64) -> Option<u64> {
+			let mut at = from;
+			// Each round moves forward to the next range or weekly opening; a handful is enough
+			// because the two lists are bounded and only move `at` forward
+			for _ in 0..32 {
+				let in_range = schedule.time_ranges.is_empty() ||
+					schedule.time_ranges.iter().any(|(start, end)| *start <= at && at < *end);
+				if !in_range {
+					at = schedule.time_ranges.iter().map(|(start, _)| *start).filter(|s| *s > at).min()?;
+					continue
+				}
+
+				let in_week = schedule.weekly.is_empty() ||
+					schedule.weekly.iter().any(|w| Self::in_weekly_window(w, at));
+				if !in_week {
+					at = schedule.weekly.iter().map(|w| Self::next_weekly_start(w, at)).min()?;
+					continue
+				}
+
+				return Some(at)
+			}
+			None
+		}
+
+		/// Minute of the week for a Unix time in milliseconds, Monday 00:00 UTC being zero
+		fn minute_of_week(at: u64) -> u64 {
+			// 1970-01-01 was a Thursday
+			(at / MS_PER_MINUTE + 3 * MINUTES_PER_DAY as u64) % MINUTES_PER_WEEK
+		}
+
+		fn in_weekly_window(window: &WeeklyWindow, at: u64) -> bool {
+			let minute = Self::minute_of_week(at);
+			let day_start = window.day as u64 * MINUTES_PER_DAY as u64;
+			day_start + window.start_minute as u64 <= minute && minute < day_start + window.end_minute as u64
+		}
+
+		/// Start of the next occurrence of a weekly window after `at`
+		fn next_weekly_start(window: &WeeklyWindow, at: u64) -> u64 {
+			let start = window.day as u64 * MINUTES_PER_DAY as u64 + window.start_minute as u64;
+			let minutes_ahead = (start + MINUTES_PER_WEEK - Self::minute_of_week(at)) % MINUTES_PER_WEEK;
+			let minutes_ahead = if minutes_ahead == 0 { MINUTES_PER_WEEK } else { minutes_ahead };
+			(at - at % MS_PER_MINUTE).saturating_add(minutes_ahead * MS_PER_MINUTE)
+		}
+
+		/// Replace a pool's config, scheduling its new expiry if that changed
+		pub(crate) fn apply_pool_config(
+			pool_id: PoolId,
+			pool_info: &mut PoolInfo<T>,
+			new_config: PoolConfig<T>,
+		) -> DispatchResult {
+			if new_config.expires_at != pool_info.config.expires_at {
+				if let Some(expires_at) = new_config.expires_at {
+					Self::schedule_expiry(pool_id, expires_at)?;
+				}
+			}
+
+			for chain in pool_info.config.allowed_chains.iter() {
+				if !new_config.allowed_chains.contains(chain) {
+					<ChainPools<T>>::remove(chain, pool_id);
+				}
+			}
+			for chain in new_config.allowed_chains.iter() {
+				<ChainPools<T>>::insert(chain, pool_id, ());
+			}
+
+			pool_info.config = new_config;
+			<Pools<T>>::insert(&pool_id, &*pool_info);
+			Self::check_low_balance(pool_id, pool_info);
+
+			Ok(())
+		}
+
+		/// Apply a pool's pending config update if it is due in block `n`
+		pub(crate) fn try_apply_config_update(pool_id: PoolId, n: BlockNumberFor<T>) {
+			// Skip queue entries for updates that were cancelled or replaced
+			let Some(pending) = Self::pending_config_update(pool_id) else { return };
+			if pending.apply_at != n {
+				return
+			}
+			<PendingConfigUpdates<T>>::remove(pool_id);
+
+			let Some(mut pool_info) = Self::pools(pool_id) else { return };
+			if pool_info.status == PoolStatus::Closed {
+				return
+			}
+
+			match Self::apply_pool_config(pool_id, &mut pool_info, pending.config) {
+				Ok(()) => Self::deposit_event(Event::PoolConfigUpdateApplied { pool_id }),
+				Err(e) => log::warn!("Config update for pool {} could not be applied: {:?}", pool_id, e),
+			}
+		}
+
+		/// Queue a pool to be expired at `expires_at`
+		pub(crate) fn schedule_expiry(pool_id: PoolId, expires_at: BlockNumberFor<T>) -> DispatchResult {
+			ensure!(
+				expires_at > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidExpiry
+			);
+
+			<ExpiryQueue<T>>::try_mutate(expires_at, |queue| {
+				queue.try_push(pool_id).map_err(|_| Error::<T>::TooManyExpiries.into())
+			})
+		}
+
+		/// Close an expired pool, unless it is already closed or still has transactions in flight.
+		/// Pools waiting on settlement are closed when their last transaction settles.
+		/// Returns whether the pool was closed.
+		pub(crate) fn try_expire_pool(pool_id: PoolId) -> bool {
+			let Some(pool_info) = Self::pools(pool_id) else { return false };
+
+			// Skip stale queue entries left behind by a config update
+			if pool_info.status == PoolStatus::Closed ||
+				!Self::is_expired(&pool_info) ||
+				!Self::pool_in_flight(pool_id).is_zero()
+			{
+				return false
+			}
+
+			let refunded = Self::do_close_pool(pool_id, pool_info);
+			Self::deposit_event(Event::PoolExpired { pool_id, refunded });
+			true
+		}
+
+		/// Add `amount` to `contributor`'s share of a pool
+		pub(crate) fn record_contribution(
+			pool_id: PoolId,
+			contributor: &T::AccountId,
+			amount: Balance<T>,
+		) -> DispatchResult {
+			let is_new = !<PoolContributors<T>>::contains_key(pool_id, contributor);
+
+			<PoolContributions<T>>::try_mutate(pool_id, |totals| -> DispatchResult {
+				if is_new {
+					ensure!(
+						totals.contributors < T::MaxContributorsPerPool::get(),
+						Error::<T>::TooManyContributors
+					);
+					totals.contributors = totals.contributors.saturating_add(1);
+				}
+				totals.total = totals.total.saturating_add(amount);
+				Ok(())
+			})?;
+
+			<PoolContributors<T>>::mutate(pool_id, contributor, |contributed| {
+				*contributed = contributed.saturating_add(amount);
+			});
+
+			Ok(())
+		}
+
+		/// Close a pool, unreserving each contributor's pro-rata share of the remaining
+		/// balance and slashing the share that was spent on sponsored fees.
+		/// Returns the total refunded.
+		pub(crate) fn do_close_pool(pool_id: PoolId, mut pool_info: PoolInfo<T>) -> Balance<T> {
+			let remaining = pool_info.balance;
+			let totals = <PoolContributions<T>>::take(pool_id);
+			let mut refunded: Balance<T> = Zero::zero();
+
+			for (contributor, contributed) in <PoolContributors<T>>::drain_prefix(pool_id) {
+				let amount = if totals.total.is_zero() {
+					Zero::zero()
+				} else {
+					Perquintill::from_rational(contributed, totals.total).mul_floor(remaining)
+				};
+
+				T::Currency::unreserve(&contributor, amount);
+				let _ = T::Currency::slash_reserved(&contributor, contributed.saturating_sub(amount));
+				refunded = refunded.saturating_add(amount);
+
+				Self::deposit_event(Event::ContributionRefunded { pool_id, contributor, amount });
+			}
+
+			pool_info.balance = Zero::zero();
+			pool_info.status = PoolStatus::Closed;
+			<Pools<T>>::insert(pool_id, &pool_info);
+			for chain in pool_info.config.allowed_chains.iter() {
+				<ChainPools<T>>::remove(chain, pool_id);
+			}
+			// Closed pools no longer count against the owner's limit
+			<OwnedPoolCount<T>>::mutate(&pool_info.owner, |count| *count = count.saturating_sub(1));
+			<AutoTopUps<T>>::remove(pool_id);
+			<LowBalanceAlerted<T>>::remove(pool_id);
+			<PendingPoolOwners<T>>::remove(pool_id);
+			<PendingConfigUpdates<T>>::remove(pool_id);
+			<PoolAllowlistRoots<T>>::remove(pool_id);
+			<VoucherSigners<T>>::remove(pool_id);
+			<PendingClosures<T>>::remove(pool_id);
+
+			Self::deposit_event(Event::PoolClosed { pool_id, refunded });
+
+			refunded
+		}
+
+		/// Queue a pool for top-up in the next block if it has fallen below its trigger
+		pub(crate) fn schedule_auto_top_up(pool_id: PoolId, pool_info: &PoolInfo<T>) {
+			let Some(state) = Self::auto_top_ups(pool_id) else { return };
+			if pool_info.balance >= state.rule.trigger_balance {
+				return
+			}
+
+			<PendingTopUps<T>>::mutate(|pending| {
+				if !pending.contains(&pool_id) && pending.try_push(pool_id).is_err() {
+					log::warn!("Auto top-up queue full, pool {} will retry on its next spend", pool_id);
+				}
+			});
+		}
+
+		/// Refill a queued pool from its owner's free balance, within the rule's caps
+		pub(crate) fn do_auto_top_up(pool_id: PoolId) {
+			let (Some(mut state), Some(mut pool_info)) =
+				(Self::auto_top_ups(pool_id), Self::pools(pool_id))
+			else {
+				return
+			};
+			if pool_info.balance >= state.rule.trigger_balance {
+				return
+			}
+
+			let today = Self::current_day();
+			if state.day != today {
+				state.day = today;
+				state.topped_up_today = Zero::zero();
+			}
+
+			let amount = state
+				.rule
+				.amount
+				.min(state.rule.daily_cap.saturating_sub(state.topped_up_today))
+				.min(state.rule.lifetime_cap.saturating_sub(state.topped_up_total));
+			if amount.is_zero() {
+				return
+			}
+
+			let owner = pool_info.owner.clone();
+			match frame_support::storage::with_storage_layer(|| {
+				Self::do_fund_pool(pool_id, &mut pool_info, &owner, amount)
+			}) {
+				Ok(new_balance) => {
+					state.topped_up_today = state.topped_up_today.saturating_add(amount);
+					state.topped_up_total = state.topped_up_total.saturating_add(amount);
+					<AutoTopUps<T>>::insert(pool_id, state);
+
+					Self::deposit_event(Event::PoolAutoToppedUp { pool_id, amount, new_balance });
+				},
+				Err(e) => {
+					log::warn!("Auto top-up of pool {} failed: {:?}", pool_id, e);
+				},
+			}
+		}
+
+		/// Emit `PoolBalanceLow` once when a pool drops below its threshold.
+		///
+		/// The alert re-arms only after the balance recovers to the threshold plus
+		/// `LOW_BALANCE_REARM_MARGIN`, so a pool hovering around it does not repeat the event.
+		pub(crate) fn check_low_balance(pool_id: PoolId, pool_info: &PoolInfo<T>) {
+			const LOW_BALANCE_REARM_MARGIN: Permill = Permill::from_percent(10);
+
+			let threshold = pool_info.config.low_balance_threshold;
+			let alerted = Self::low_balance_alerted(pool_id);
+
+			if threshold.is_zero() {
+				if alerted {
+					<LowBalanceAlerted<T>>::remove(pool_id);
+				}
+				return
+			}
+
+			if !alerted && pool_info.balance < threshold {
+				<LowBalanceAlerted<T>>::insert(pool_id, true);
+				Self::deposit_event(Event::PoolBalanceLow {
+					pool_id,
+					balance: pool_info.balance,
+					threshold,
+				});
+			} else if alerted &&
+				pool_info.balance >=
+					threshold.saturating_add(LOW_BALANCE_REARM_MARGIN.mul_ceil(threshold))
+			{
+				<LowBalanceAlerted<T>>::remove(pool_id);
+			}
+		}
+
+		/// Fold a settled transaction into the pool's daily snapshot and the user's spending
+		fn record_settlement(
+			record: &TransactionRecord<T>,
+			status: &TransactionStatus,
+			actual_cost: Balance<T>,
+		) {
+			let is_new_user = !actual_cost.is_zero() &&
+				Self::user_spending(record.pool_id, &record.user).total_spent.is_zero();
+
+			<UserSpending<T>>::mutate(record.pool_id, &record.user, |spending| {
+				spending.total_spent = spending.total_spent.saturating_add(actual_cost);
+			});
+
+			<PoolDailyStats<T>>::mutate(record.pool_id, Self::current_day(), |snapshot| {
+				match status {
+					TransactionStatus::Executed =>
+						snapshot.executed_count = snapshot.executed_count.saturating_add(1),
+					TransactionStatus::Failed =>
+						snapshot.failed_count = snapshot.failed_count.saturating_add(1),
+					TransactionStatus::Refunded =>
+						snapshot.refunded_count = snapshot.refunded_count.saturating_add(1),
+					TransactionStatus::Pending | TransactionStatus::Dispatched => {},
+				}
+
+				snapshot.total_spent = snapshot.total_spent.saturating_add(actual_cost);
+				if is_new_user {
+					snapshot.new_user_count = snapshot.new_user_count.saturating_add(1);
+					snapshot.new_user_spent = snapshot.new_user_spent.saturating_add(actual_cost);
+				} else {
+					snapshot.returning_user_spent =
+						snapshot.returning_user_spent.saturating_add(actual_cost);
+				}
+
+				match snapshot.chains.iter_mut().find(|c| c.target_chain == record.target_chain) {
+					Some(entry) => {
+						entry.settled_count = entry.settled_count.saturating_add(1);
+						entry.total_spent = entry.total_spent.saturating_add(actual_cost);
+					},
+					None => {
+						// Bounded by the 100 allowed chains per pool
+						let _ = snapshot.chains.try_push(ChainSpend {
+							target_chain: record.target_chain,
+							settled_count: 1,
+							total_spent: actual_cost,
+						});
+					},
+				}
+			});
+		}
+
+		/// Day index of the given block
+		pub fn day_of(block_number: BlockNumberFor<T>) -> u32 {
+			block_number
+				.checked_div(&T::BlocksPerDay::get())
+				.unwrap_or_else(Zero::zero)
+				.saturated_into::<u32>()
+		}
+
+		/// Day index of the current block
+		pub fn current_day() -> u32 {
+			Self::day_of(<frame_system::Pallet<T>>::block_number())
+		}
+
+		/// Pools `sponsor_transaction_auto` considers for a caller-supplied list of
+		/// `supplied` pools; an empty list selects from the `ChainPools` index
+		pub fn auto_select_candidate_count(supplied: u32) -> u32 {
+			if supplied == 0 {
+				T::MaxAutoSelectCandidates::get()
+			} else {
+				supplied
+			}
+		}
+
+		/// Keep the `candidates` that are active pools able to sponsor a transaction to
+		/// `target_chain`, ordered by how well they match the preference. Only the given
+		/// pools are read, so the cost is bounded by the caller's list.
+		pub fn select_candidate_pools(
+			target_chain: u32,
+			candidates: &[PoolId],
+			preference: &PoolSelectionPreference<T>,
+		) -> Vec<PoolId> {
+			let is_eligible = |pool_info: &PoolInfo<T>| {
+				pool_info.status == PoolStatus::Active &&
+					pool_info.config.allowed_chains.contains(&target_chain) &&
+					!Self::is_expired(pool_info) &&
+					pool_info
+						.config
+						.schedule
+						.as_ref()
+						.map_or(true, |schedule| Self::schedule_window(schedule) == SponsorshipWindow::Open)
+			};
+			let is_preferred = |pool_info: &PoolInfo<T>| match preference {
+				PoolSelectionPreference::Owner(owner) => pool_info.owner == *owner,
+				PoolSelectionPreference::CampaignTag(tag) => pool_info.config.campaign_tag.as_ref() == Some(tag),
+				PoolSelectionPreference::Any | PoolSelectionPreference::CheapestCoverage => true,
+			};
+
+			let mut pool_ids = candidates.to_vec();
+			pool_ids.sort();
+			pool_ids.dedup();
+			let mut candidates: Vec<(PoolId, PoolInfo<T>)> = pool_ids
+				.into_iter()
+				.filter_map(|pool_id| Self::pools(pool_id).map(|info| (pool_id, info)))
+				.filter(|(_, info)| is_eligible(info) && is_preferred(info))
+				.collect();
+
+			match preference {
+				PoolSelectionPreference::CheapestCoverage => {
+					// Prefer the tightest fit so larger pools are kept for larger transactions
+					candidates.sort_by(|(a_id, a), (b_id, b)| {
+						a.balance.cmp(&b.balance).then(a_id.cmp(b_id))
+					});
+				},
+				_ => candidates.sort_by_key(|(pool_id, _)| *pool_id),
+			}
+
+			candidates.into_iter().map(|(pool_id, _)| pool_id).collect()
 		}
 
 		/// Get pool information by ID (for RPC)
@@ -629,6 +3544,219 @@ pub mod pallet {
 			transactions
 		}
 
+		/// Get a page of transaction history for a pool, newest first (for RPC)
+		///
+		/// Scans transaction IDs downwards from `cursor` (exclusive) and returns the
+		/// matching records plus the cursor to resume from, if any IDs remain.
+		pub fn get_pool_transaction_page(
+			pool_id: PoolId,
+			cursor: Option<u64>,
+			limit: u32,
+		) -> (Vec<TransactionRecord<T>>, Option<u64>) {
+			// Bound the number of IDs visited per call so sparse pools can't exhaust the call
+			const MAX_SCAN: u64 = 1000;
+
+			let start = cursor.unwrap_or_else(Self::next_transaction_id).min(Self::next_transaction_id());
+			let mut transactions = Vec::new();
+			let mut tx_id = start;
+
+			while tx_id > 0 &&
+				(transactions.len() as u32) < limit &&
+				start.saturating_sub(tx_id) < MAX_SCAN
+			{
+				tx_id -= 1;
+				if let Some(tx_record) = Self::transaction_log(tx_id) {
+					if tx_record.pool_id == pool_id {
+						transactions.push(tx_record);
+					}
+				}
+			}
+
+			let next_cursor = if tx_id > 0 { Some(tx_id) } else { None };
+			(transactions, next_cursor)
+		}
+
+		/// Get pool statistics aggregated from daily snapshots (for RPC)
+		///
+		/// Defaults to the last `MAX_STATS_DAYS` days up to the current block.
+		pub fn get_pool_stats(
+			pool_id: PoolId,
+			from_block: Option<BlockNumberFor<T>>,
+			to_block: Option<BlockNumberFor<T>>,
+		) -> PoolStats<Balance<T>> {
+			// Bound the number of snapshots read per call
+			const MAX_STATS_DAYS: u32 = 366;
+
+			let to_day = to_block.map(Self::day_of).unwrap_or_else(Self::current_day);
+			let earliest_day = to_day.saturating_sub(MAX_STATS_DAYS - 1);
+			let from_day = from_block.map(Self::day_of).unwrap_or(earliest_day).max(earliest_day);
+
+			let mut stats = PoolStats {
+				from_day,
+				to_day,
+				sponsored_count: 0,
+				pending_count: 0,
+				executed_count: 0,
+				failed_count: 0,
+				refunded_count: 0,
+				total_spent: Zero::zero(),
+				average_fee: Zero::zero(),
+				new_user_count: 0,
+				new_user_spent: Zero::zero(),
+				returning_user_spent: Zero::zero(),
+				by_chain: Vec::new(),
+				by_day: Vec::new(),
+			};
+
+			for day in from_day..=to_day {
+				if !<PoolDailyStats<T>>::contains_key(pool_id, day) {
+					continue;
+				}
+				let snapshot = Self::pool_daily_stats(pool_id, day);
+
+				stats.sponsored_count = stats.sponsored_count.saturating_add(snapshot.sponsored_count);
+				stats.executed_count = stats.executed_count.saturating_add(snapshot.executed_count);
+				stats.failed_count = stats.failed_count.saturating_add(snapshot.failed_count);
+				stats.refunded_count = stats.refunded_count.saturating_add(snapshot.refunded_count);
+				stats.total_spent = stats.total_spent.saturating_add(snapshot.total_spent);
+				stats.new_user_count = stats.new_user_count.saturating_add(snapshot.new_user_count);
+				stats.new_user_spent = stats.new_user_spent.saturating_add(snapshot.new_user_spent);
+				stats.returning_user_spent =
+					stats.returning_user_spent.saturating_add(snapshot.returning_user_spent);
+
+				for chain in snapshot.chains.iter() {
+					match stats.by_chain.iter_mut().find(|c| c.target_chain == chain.target_chain) {
+						Some(entry) => {
+							entry.settled_count = entry.settled_count.saturating_add(chain.settled_count);
+							entry.total_spent = entry.total_spent.saturating_add(chain.total_spent);
+						},
+						None => stats.by_chain.push(chain.clone()),
+					}
+				}
+
+				stats.by_day.push((day, snapshot));
+			}
+
+			let settled = stats.executed_count.saturating_add(stats.failed_count);
+			stats.pending_count = stats
+				.sponsored_count
+				.saturating_sub(settled)
+				.saturating_sub(stats.refunded_count);
+			if settled > 0 {
+				stats.average_fee = stats.total_spent / settled.into();
+			}
+
+			stats
+		}
+
+		/// Project when a pool runs out from its recent daily snapshots (for RPC)
+		///
+		/// The burn rate averages settled spend over the last `FORECAST_WINDOW_DAYS` days,
+		/// or fewer if the pool is younger than that.
+		pub fn forecast_pool_runway(
+			pool_id: PoolId,
+			target_runway_days: u32,
+		) -> Option<PoolForecast<Balance<T>, BlockNumberFor<T>>> {
+			const FORECAST_WINDOW_DAYS: u32 = 7;
+
+			let pool_info = Self::pools(pool_id)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			let today = Self::day_of(now);
+			let created_day = Self::day_of(pool_info.created_at);
+			let sample_days =
+				today.saturating_sub(created_day).saturating_add(1).min(FORECAST_WINDOW_DAYS);
+			let first_day = today.saturating_sub(sample_days - 1);
+
+			let spent = (first_day..=today).fold(Zero::zero(), |acc: Balance<T>, day| {
+				acc.saturating_add(Self::pool_daily_stats(pool_id, day).total_spent)
+			});
+			let daily_burn_rate = spent / sample_days.into();
+
+			let (runway_days, depletion_block, recommended_top_up) = if daily_burn_rate.is_zero() {
+				(None, None, Zero::zero())
+			} else {
+				let balance = pool_info.balance.saturated_into::<u128>();
+				let burn = daily_burn_rate.saturated_into::<u128>();
+				let blocks_per_day = T::BlocksPerDay::get().saturated_into::<u128>();
+
+				let runway_days = (balance / burn).saturated_into::<u32>();
+				let runway_blocks = balance.saturating_mul(blocks_per_day) / burn;
+				let depletion_block = now.saturating_add(runway_blocks.saturated_into());
+				let target = daily_burn_rate.saturating_mul(target_runway_days.into());
+
+				(Some(runway_days), Some(depletion_block), target.saturating_sub(pool_info.balance))
+			};
+
+			Some(PoolForecast {
+				balance: pool_info.balance,
+				daily_burn_rate,
+				sample_days,
+				runway_days,
+				depletion_block,
+				recommended_top_up,
+			})
+		}
+
+		/// Simulate sponsoring a transaction without committing any state (for RPC)
+		///
+		/// `credential` is the SCALE-encoded `SponsorshipCredential` the caller would submit.
+		pub fn dry_run_sponsorship(
+			who: T::AccountId,
+			pool_id: PoolId,
+			target_chain: u32,
+			call_data: Vec<u8>,
+			credential: Option<Vec<u8>>,
+		) -> DryRunResult<Balance<T>> {
+			let estimated_fee = Self::estimate_transaction_gas_cost(target_chain, &call_data);
+
+			let credential = match credential.map(|encoded| SponsorshipCredential::<T>::decode(&mut &encoded[..])) {
+				Some(Err(_)) => {
+					let name: &'static str = Error::<T>::InvalidCredential.into();
+					return DryRunResult {
+						estimated_fee,
+						pool_balance_after: None,
+						error: Some(name.as_bytes().to_vec()),
+					}
+				},
+				Some(Ok(credential)) => Some(credential),
+				None => None,
+			};
+
+			// Run the real sponsorship path, then roll every write back
+			let (result, balance_after) = frame_support::storage::with_transaction(|| {
+				let result =
+					Self::do_sponsor_transaction(&who, pool_id, target_chain, call_data, credential.as_ref());
+				let balance_after = Self::pools(pool_id).map(|pool_info| pool_info.balance);
+				frame_support::storage::TransactionOutcome::Rollback(Ok::<_, DispatchError>((
+					result,
+					balance_after,
+				)))
+			})
+			.unwrap_or_else(|e| (Err(e), None));
+
+			match result {
+				Ok(_) => DryRunResult { estimated_fee, pool_balance_after: balance_after, error: None },
+				Err(e) => {
+					let name: &'static str = e.into();
+					DryRunResult {
+						estimated_fee,
+						pool_balance_after: None,
+						error: Some(name.as_bytes().to_vec()),
+					}
+				},
+			}
+		}
+
+		/// Get the fee estimate split into its components (for RPC)
+		pub fn estimate_fee_breakdown(target_chain: u32, call_data: &[u8]) -> FeeBreakdown<Balance<T>> {
+			FeeEstimator::<T>::estimate_breakdown_or_fallback(target_chain, call_data)
+		}
+
+		/// Get a single transaction record (for RPC)
+		pub fn get_transaction_record(transaction_id: u64) -> Option<TransactionRecord<T>> {
+			Self::transaction_log(transaction_id)
+		}
+
 		/// Get user gas savings (for RPC)
 		pub fn get_user_total_gas_savings(user: &T::AccountId) -> Balance<T> {
 			let mut total_savings = Zero::zero();
@@ -645,16 +3773,20 @@ pub mod pallet {
 
 		/// Estimate gas cost for a transaction (for RPC)
 		pub fn estimate_transaction_gas_cost(target_chain: u32, call_data: &[u8]) -> Balance<T> {
-			// Use the XCM Gateway fee estimator
-			FeeEstimator::<T>::estimate_fee(target_chain, call_data)
-				.unwrap_or_else(|_| {
-					// Fallback estimation based on call data size
-					let base_fee = 1000u32.into(); // Base fee in smallest unit
-					let per_byte_fee = 10u32.into(); // Per byte fee
-					let call_size: u32 = call_data.len().try_into().unwrap_or(0);
-					
-					base_fee.saturating_add(per_byte_fee.saturating_mul(call_size.into()))
-				})
-		}
-	}
-}
\ No newline at end of file
+			// Unsupported chains are quoted with the fallback base fee
+			FeeEstimator::<T>::estimate_breakdown_or_fallback(target_chain, call_data).total
+		}
+	}
+
+	impl<T: Config> frame_support::traits::OnNewAccount<T::AccountId> for Pallet<T> {
+		fn on_new_account(who: &T::AccountId) {
+			<AccountFirstSeen<T>>::insert(who, <frame_system::Pallet<T>>::block_number());
+		}
+	}
+
+	impl<T: Config> frame_support::traits::OnKilledAccount<T::AccountId> for Pallet<T> {
+		fn on_killed_account(who: &T::AccountId) {
+			<AccountFirstSeen<T>>::remove(who);
+		}
+	}
+}
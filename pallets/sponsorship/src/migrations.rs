@@ -0,0 +1,203 @@
+//! Storage migrations for the sponsorship pallet.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData};
+
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Storage layout before rules were keyed by id and pools gained schedules, expiry and
+/// delayed config updates.
+pub mod v0 {
+	use super::*;
+
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct PoolConfig<T: Config> {
+		pub max_transaction_value: Balance<T>,
+		pub daily_spending_limit: Balance<T>,
+		pub allowed_chains: BoundedVec<u32, ConstU32<100>>,
+		pub authorization_required: bool,
+	}
+
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct PoolInfo<T: Config> {
+		pub owner: T::AccountId,
+		pub balance: Balance<T>,
+		pub total_spent: Balance<T>,
+		pub created_at: BlockNumberFor<T>,
+		pub config: PoolConfig<T>,
+		pub status: PoolStatus,
+	}
+
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum AuthorizationType {
+		UserWhitelist,
+		TransactionType,
+		SpendingLimit,
+	}
+
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct AuthorizationRule<T: Config> {
+		pub rule_type: AuthorizationType,
+		pub data: BoundedVec<u8, ConstU32<256>>,
+		pub created_at: BlockNumberFor<T>,
+	}
+
+	#[frame_support::storage_alias]
+	pub type Pools<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, PoolId, PoolInfo<T>>;
+
+	#[frame_support::storage_alias]
+	pub type PoolAuthorizations<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		PoolId,
+		Blake2_128Concat,
+		AuthorizationType,
+		AuthorizationRule<T>,
+		OptionQuery,
+	>;
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// How many of the most recent transaction ids are checked for transactions still in
+	/// flight. Receipts arrive within a few blocks of dispatch, so older entries in the
+	/// ever-growing `TransactionLog` have settled and scanning it all would not fit in a block.
+	/// An older transaction that is somehow still pending settles as usual, it is just not
+	/// counted in `PoolInFlight`.
+	pub const IN_FLIGHT_SCAN_WINDOW: u64 = 1_000;
+
+	/// Migrates pools to the current `PoolConfig` and rebuilds the storage derived from them,
+	/// including the `ChainPools` index of open pools.
+	///
+	/// Rules stored under the old `(PoolId, AuthorizationType)` keys held opaque data that was
+	/// never evaluated, so they cannot be translated and are removed. Pools that require
+	/// authorization sponsor nobody until their owner adds rules.
+	///
+	/// Pools could only be funded by their owner, so the owner is recorded as the only
+	/// contributor of everything reserved for the pool: its balance plus what it spent.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 0 {
+				log::info!(
+					target: LOG_TARGET,
+					"skipping v1 migration: on-chain storage version is {:?}",
+					on_chain
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut reads = 1u64;
+			let mut writes = 0u64;
+
+			let rules = v0::PoolAuthorizations::<T>::drain().count() as u64;
+			reads.saturating_accrue(rules);
+			writes.saturating_accrue(rules);
+
+			let mut owned: BTreeMap<T::AccountId, u32> = BTreeMap::new();
+			let mut pools = 0u64;
+			Pools::<T>::translate::<v0::PoolInfo<T>, _>(|pool_id, old| {
+				pools.saturating_inc();
+				let config = PoolConfig {
+					max_transaction_value: old.config.max_transaction_value,
+					daily_spending_limit: old.config.daily_spending_limit,
+					allowed_chains: old.config.allowed_chains,
+					authorization_required: old.config.authorization_required,
+					campaign_tag: None,
+					low_balance_threshold: Zero::zero(),
+					expires_at: None,
+					schedule: None,
+					config_update_delay: Zero::zero(),
+				};
+
+				if old.status != PoolStatus::Closed {
+					let contributed = old.balance.saturating_add(old.total_spent);
+					PoolContributors::<T>::insert(pool_id, &old.owner, contributed);
+					PoolContributions::<T>::insert(
+						pool_id,
+						ContributionTotals { total: contributed, contributors: 1 },
+					);
+					for chain in config.allowed_chains.iter() {
+						ChainPools::<T>::insert(chain, pool_id, ());
+					}
+					writes.saturating_accrue(2 + config.allowed_chains.len() as u64);
+					owned.entry(old.owner.clone()).or_default().saturating_inc();
+				}
+
+				Some(PoolInfo {
+					owner: old.owner,
+					balance: old.balance,
+					total_spent: old.total_spent,
+					created_at: old.created_at,
+					config,
+					status: old.status,
+				})
+			});
+			reads.saturating_accrue(pools);
+			writes.saturating_accrue(pools);
+
+			for (owner, count) in owned {
+				OwnedPoolCount::<T>::insert(owner, count);
+				writes.saturating_inc();
+			}
+
+			// Transactions dispatched before the upgrade still settle through
+			// `process_receipt`, which expects them counted as in flight
+			let next_id = NextTransactionId::<T>::get();
+			reads.saturating_inc();
+			let mut in_flight: BTreeMap<PoolId, u32> = BTreeMap::new();
+			for id in next_id.saturating_sub(IN_FLIGHT_SCAN_WINDOW)..next_id {
+				reads.saturating_inc();
+				if let Some(record) = TransactionLog::<T>::get(id) {
+					if matches!(record.status, TransactionStatus::Pending | TransactionStatus::Dispatched) {
+						in_flight.entry(record.pool_id).or_default().saturating_inc();
+					}
+				}
+			}
+			for (pool_id, count) in in_flight {
+				PoolInFlight::<T>::insert(pool_id, count);
+				writes.saturating_inc();
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			writes.saturating_inc();
+
+			log::info!(
+				target: LOG_TARGET,
+				"migrated {} pools to v1 and removed {} untranslatable rules",
+				pools,
+				rules
+			);
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let pools = v0::Pools::<T>::iter_keys().count() as u32;
+			Ok(pools.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let pools: u32 = Decode::decode(&mut &state[..])
+				.map_err(|_| "pre_upgrade state does not decode")?;
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "storage version not set to 1");
+			ensure!(Pools::<T>::iter_values().count() as u32 == pools, "pools lost in migration");
+			ensure!(PoolAuthorizations::<T>::iter_keys().count() == 0, "old rules left behind");
+			Ok(())
+		}
+	}
+}
@@ -1,12 +1,15 @@
 use crate as pallet_sponsorship;
 use frame_support::{
 	parameter_types,
-	traits::{ConstU16, ConstU32, ConstU64, ConstU128},
+	traits::{AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, ConstU128, UnixTime},
+	weights::ConstantMultiplier,
 	PalletId,
 };
+use frame_system::{EnsureRoot, EnsureSigned};
 use sp_core::H256;
 use sp_runtime::{
-	traits::{BlakeTwo256, IdentityLookup}, BuildStorage,
+	testing::{TestSignature, UintAuthorityId},
+	traits::{BlakeTwo256, IdentityLookup}, BuildStorage, Permill,
 };
 
 // Configure a mock runtime to test the pallet.
@@ -15,6 +18,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system,
 		Balances: pallet_balances,
+		Assets: pallet_assets,
 		Sponsorship: pallet_sponsorship,
 	}
 );
@@ -37,8 +41,8 @@ impl frame_system::Config for Test {
 	type Version = ();
 	type PalletInfo = PalletInfo;
 	type AccountData = pallet_balances::AccountData<u128>;
-	type OnNewAccount = ();
-	type OnKilledAccount = ();
+	type OnNewAccount = Sponsorship;
+	type OnKilledAccount = Sponsorship;
 	type SystemWeightInfo = ();
 	type SS58Prefix = ConstU16<42>;
 	type OnSetCode = ();
@@ -63,6 +67,100 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
 	pub const SponsorshipPalletId: PalletId = PalletId(*b"gasleap!");
+	pub static XcmDeliveryFee: u128 = 0;
+	pub static ProtocolFee: Permill = Permill::zero();
+	pub static FeeSafetyMargin: Permill = Permill::zero();
+	pub static WeightFeeMultiplier: u128 = 0;
+	/// Unix time in milliseconds reported by `MockTime`
+	pub static Now: u64 = 0;
+	/// NFTs reported by `MockNfts` as `(collection, item, owner)`
+	pub static NftHolders: Vec<(u32, u32, u64)> = Vec::new();
+	/// Good judgements reported by `MockIdentity` as `(account, registrar)`
+	pub static GoodJudgements: Vec<(u64, u32)> = Vec::new();
+}
+
+pub struct MockTime;
+
+impl UnixTime for MockTime {
+	fn now() -> core::time::Duration {
+		core::time::Duration::from_millis(Now::get())
+	}
+}
+
+pub struct MockNfts;
+
+impl pallet_sponsorship::InspectNftHolder<u64> for MockNfts {
+	type CollectionId = u32;
+	type ItemId = u32;
+
+	fn owned_items(collection: &u32, who: &u64, limit: u32) -> Vec<u32> {
+		NftHolders::get()
+			.into_iter()
+			.filter(|(c, _, owner)| c == collection && owner == who)
+			.map(|(_, item, _)| item)
+			.take(limit as usize)
+			.collect()
+	}
+}
+
+pub struct MockIdentity;
+
+impl pallet_sponsorship::InspectIdentity<u64> for MockIdentity {
+	fn has_good_judgement(who: &u64, registrars: &[u32]) -> bool {
+		GoodJudgements::get()
+			.iter()
+			.any(|(account, registrar)| account == who && registrars.contains(registrar))
+	}
+}
+
+/// Test signatures are the signer's id plus the signed message
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockVoucherSigner;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_sponsorship::BenchmarkHelper<u64, TestSignature, u32> for MockVoucherSigner {
+	fn sign(message: &[u8]) -> (u64, TestSignature) {
+		(7, TestSignature(7, message.to_vec()))
+	}
+
+	fn mint_nfts(who: &u64, items: u32) -> u32 {
+		let mut holders = NftHolders::get();
+		let collection = holders.iter().map(|(c, _, _)| c + 1).max().unwrap_or_default();
+		holders.extend((0..items).map(|item| (collection, item, *who)));
+		NftHolders::set(holders);
+		collection
+	}
+
+	fn judge_identity(who: &u64) -> u32 {
+		let mut judgements = GoodJudgements::get();
+		let registrar = judgements.len() as u32;
+		judgements.push((*who, registrar));
+		GoodJudgements::set(judgements);
+		registrar
+	}
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u128;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+	type ForceOrigin = EnsureRoot<u64>;
+	type AssetDeposit = ConstU128<1>;
+	type AssetAccountDeposit = ConstU128<10>;
+	type MetadataDepositBase = ConstU128<1>;
+	type MetadataDepositPerByte = ConstU128<1>;
+	type ApprovalDeposit = ConstU128<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 impl pallet_sponsorship::Config for Test {
@@ -70,8 +168,37 @@ impl pallet_sponsorship::Config for Test {
 	type Currency = Balances;
 	type PalletId = SponsorshipPalletId;
 	type MaxPoolsPerAccount = ConstU32<100>;
-	type MaxAuthorizationsPerPool = ConstU32<1000>;
+	type MaxAuthorizationsPerPool = ConstU32<16>;
+	type MaxRuleNodes = ConstU32<16>;
+	type MaxRuleDepth = ConstU32<4>;
 	type MinPoolDeposit = ConstU128<1000>;
+	type WhitelistDepositPerAccount = ConstU128<10>;
+	type MaxWhitelistBatch = ConstU32<16>;
+	type MaxMerkleProofDepth = ConstU32<16>;
+	type MaxAttestersPerPool = ConstU32<4>;
+	type MaxAutoSelectCandidates = ConstU32<16>;
+	type WeightToFee = ConstantMultiplier<u128, WeightFeeMultiplier>;
+	type XcmDeliveryFee = XcmDeliveryFee;
+	type ProtocolFee = ProtocolFee;
+	type FeeSafetyMargin = FeeSafetyMargin;
+	type BlocksPerDay = ConstU64<100>;
+	type MaxPendingTopUps = ConstU32<16>;
+	type MaxContributorsPerPool = ConstU32<16>;
+	type MaxExpiriesPerBlock = ConstU32<16>;
+	type MaxVoucherExpiriesPerBlock = ConstU32<16>;
+	type MaxRevocationExpiriesPerBlock = ConstU32<16>;
+	type MaxConfigUpdatesPerBlock = ConstU32<16>;
+	type AdminOrigin = EnsureRoot<u64>;
+	type ReceiptOrigin = EnsureRoot<u64>;
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = UintAuthorityId;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockVoucherSigner;
+	type Assets = Assets;
+	type Nfts = MockNfts;
+	type MaxNftItemsChecked = ConstU32<2>;
+	type Identity = MockIdentity;
+	type UnixTime = MockTime;
 	type WeightInfo = ();
 }
 
@@ -1,5 +1,8 @@
-use crate::{mock::*, Error, Event, PoolConfig, PoolStatus, AuthorizationType, AuthorizationRule};
-use frame_support::{assert_noop, assert_ok, BoundedVec};
+use crate::{mock::*, AllowlistProof, Attestation, AutoTopUpRule, SignedAttestation, SignedVoucher, SponsorshipCredential, SponsorshipVoucher, VoucherCall, Error, Event, PoolConfig, PoolStatus, RuleNode, PoolSelectionPreference, TransactionReceipt, ExecutionResult, TransactionStatus, PoolRole, SponsorshipSchedule, SponsorshipWindow, WeeklyWindow};
+use frame_support::{assert_noop, assert_ok, traits::{ConstU32, Hooks}, BoundedVec};
+use codec::Encode;
+use sp_core::H256;
+use sp_runtime::{testing::TestSignature, traits::{BlakeTwo256, Hash}};
 
 #[test]
 fn create_pool_works() {
@@ -12,6 +15,11 @@ fn create_pool_works() {
 			daily_spending_limit: 5000,
 			allowed_chains: BoundedVec::try_from(vec![2000, 2006]).unwrap(), // Acala and Astar
 			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		// Create pool should work
@@ -46,6 +54,11 @@ fn create_pool_fails_with_insufficient_deposit() {
 			daily_spending_limit: 5000,
 			allowed_chains: BoundedVec::try_from(vec![1000]).unwrap(),
 			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		// Should fail with deposit too small
@@ -66,6 +79,11 @@ fn fund_pool_works() {
 			daily_spending_limit: 5000,
 			allowed_chains: BoundedVec::try_from(vec![1000]).unwrap(),
 			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		// Create pool first
@@ -81,6 +99,7 @@ fn fund_pool_works() {
 		// Check that event was emitted
 		System::assert_last_event(Event::PoolFunded {
 			pool_id: 0,
+			contributor: 1,
 			amount: 1000,
 			new_balance: 3000,
 		}.into());
@@ -88,23 +107,42 @@ fn fund_pool_works() {
 }
 
 #[test]
-fn fund_pool_fails_for_non_owner() {
+fn fund_pool_accepts_third_party_contributions() {
 	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
 		let config = PoolConfig {
 			max_transaction_value: 1000,
 			daily_spending_limit: 5000,
 			allowed_chains: BoundedVec::try_from(vec![1000]).unwrap(),
 			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		// Create pool with account 1
 		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config));
 
-		// Try to fund with account 2 (should fail)
-		assert_noop!(
-			Sponsorship::fund_pool(RuntimeOrigin::signed(2), 0, 1000),
-			Error::<Test>::NotPoolOwner
-		);
+		// Account 2 co-funds the pool
+		assert_ok!(Sponsorship::fund_pool(RuntimeOrigin::signed(2), 0, 1000));
+		assert_ok!(Sponsorship::fund_pool(RuntimeOrigin::signed(2), 0, 500));
+
+		assert_eq!(Sponsorship::pools(0).unwrap().balance, 3500);
+		assert_eq!(Sponsorship::pool_contributors(0, 1), 2000);
+		assert_eq!(Sponsorship::pool_contributors(0, 2), 1500);
+		assert_eq!(Sponsorship::pool_contributions(0).total, 3500);
+		assert_eq!(Sponsorship::pool_contributions(0).contributors, 2);
+		assert_eq!(Balances::reserved_balance(2), 1500);
+
+		System::assert_last_event(Event::PoolFunded {
+			pool_id: 0,
+			contributor: 2,
+			amount: 500,
+			new_balance: 3500,
+		}.into());
 	});
 }
 
@@ -118,6 +156,11 @@ fn sponsor_transaction_works() {
 			daily_spending_limit: 5000,
 			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
 			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		// Create pool
@@ -128,7 +171,8 @@ fn sponsor_transaction_works() {
 			RuntimeOrigin::signed(2),
 			0,
 			2006, // Astar parachain ID
-			vec![1, 2, 3, 4]
+			vec![1, 2, 3, 4],
+			None
 		));
 
 		// Check that transaction was logged
@@ -153,6 +197,45 @@ fn sponsor_transaction_works() {
 	});
 }
 
+#[test]
+fn sponsor_transaction_respects_daily_spending_limit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 2000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+
+		let sponsor =
+			|who: u64| Sponsorship::sponsor_transaction(RuntimeOrigin::signed(who), 0, 2006, vec![1, 2, 3, 4], None);
+
+		// Two Astar fees fit in the limit, across users
+		assert_ok!(sponsor(2));
+		assert_ok!(sponsor(3));
+		assert_eq!(Sponsorship::pool_daily_stats(0, 0).reserved, 1616);
+		assert_noop!(sponsor(2), Error::<Test>::ExceedsSpendingLimit);
+
+		// Refunds do not free up today's limit
+		assert_ok!(Sponsorship::process_receipt(RuntimeOrigin::root(), receipt(0, 2006, ExecutionResult::Success, 400)));
+		assert_noop!(sponsor(2), Error::<Test>::ExceedsSpendingLimit);
+
+		// The limit starts over the next day
+		System::set_block_number(100);
+		assert_ok!(sponsor(2));
+		assert_eq!(Sponsorship::pool_daily_stats(0, 1).reserved, 808);
+	});
+}
+
 #[test]
 fn sponsor_transaction_fails_for_unsupported_chain() {
 	new_test_ext().execute_with(|| {
@@ -161,6 +244,11 @@ fn sponsor_transaction_fails_for_unsupported_chain() {
 			daily_spending_limit: 5000,
 			allowed_chains: BoundedVec::try_from(vec![1000]).unwrap(),
 			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		// Create pool
@@ -172,7 +260,8 @@ fn sponsor_transaction_fails_for_unsupported_chain() {
 				RuntimeOrigin::signed(2),
 				0,
 				2000, // Chain not in allowed_chains
-				vec![1, 2, 3, 4]
+				vec![1, 2, 3, 4],
+				None
 			),
 			Error::<Test>::ChainNotSupported
 		);
@@ -190,6 +279,11 @@ fn update_pool_config_works() {
 			daily_spending_limit: 5000,
 			allowed_chains: BoundedVec::try_from(vec![1000]).unwrap(),
 			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		// Create pool
@@ -201,6 +295,11 @@ fn update_pool_config_works() {
 			daily_spending_limit: 10000,
 			allowed_chains: BoundedVec::try_from(vec![1000, 2000, 3000]).unwrap(),
 			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		assert_ok!(Sponsorship::update_pool_config(RuntimeOrigin::signed(1), 0, new_config.clone()));
@@ -228,6 +327,11 @@ fn update_pool_config_fails_for_non_owner() {
 			daily_spending_limit: 5000,
 			allowed_chains: BoundedVec::try_from(vec![1000]).unwrap(),
 			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		// Create pool with account 1
@@ -249,6 +353,11 @@ fn update_pool_config_fails_for_nonexistent_pool() {
 			daily_spending_limit: 5000,
 			allowed_chains: BoundedVec::try_from(vec![1000]).unwrap(),
 			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		// Try to update config for non-existent pool
@@ -260,6 +369,10 @@ fn update_pool_config_fails_for_nonexistent_pool() {
 }
 
 // Authorization rule tests
+fn rule(nodes: Vec<RuleNode<Test>>) -> BoundedVec<RuleNode<Test>, ConstU32<16>> {
+	BoundedVec::try_from(nodes).unwrap()
+}
+
 #[test]
 fn add_authorization_rule_works() {
 	new_test_ext().execute_with(|| {
@@ -270,30 +383,32 @@ fn add_authorization_rule_works() {
 			daily_spending_limit: 5000,
 			allowed_chains: BoundedVec::try_from(vec![1000]).unwrap(),
 			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		// Create pool
 		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config));
 
 		// Create authorization rule
-		let rule = AuthorizationRule {
-			rule_type: AuthorizationType::UserWhitelist,
-			data: BoundedVec::try_from(vec![2u8, 3u8, 4u8]).unwrap(), // Whitelisted user IDs
-			created_at: 1,
-		};
+		let condition = rule(vec![RuleNode::AllowAccounts(BoundedVec::try_from(vec![2, 3, 4]).unwrap())]);
 
 		// Add authorization rule
-		assert_ok!(Sponsorship::add_authorization_rule(RuntimeOrigin::signed(1), 0, rule.clone()));
+		assert_ok!(Sponsorship::add_authorization_rule(RuntimeOrigin::signed(1), 0, condition.clone()));
 
 		// Check that rule was stored
-		let stored_rule = Sponsorship::pool_authorizations(0, AuthorizationType::UserWhitelist).unwrap();
-		assert_eq!(stored_rule.rule_type, rule.rule_type);
-		assert_eq!(stored_rule.data, rule.data);
+		let stored_rule = Sponsorship::pool_authorizations(0, 0).unwrap();
+		assert_eq!(stored_rule.condition, condition);
+		assert_eq!(stored_rule.created_at, 1);
+		assert_eq!(Sponsorship::pool_rule_count(0), 1);
 
 		// Check that event was emitted
 		System::assert_last_event(Event::AuthorizationRuleAdded {
 			pool_id: 0,
-			rule_type: AuthorizationType::UserWhitelist,
+			rule_id: 0,
 		}.into());
 	});
 }
@@ -306,20 +421,21 @@ fn add_authorization_rule_fails_for_non_owner() {
 			daily_spending_limit: 5000,
 			allowed_chains: BoundedVec::try_from(vec![1000]).unwrap(),
 			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		// Create pool with account 1
 		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config));
 
-		let rule = AuthorizationRule {
-			rule_type: AuthorizationType::UserWhitelist,
-			data: BoundedVec::try_from(vec![2u8]).unwrap(),
-			created_at: 1,
-		};
+		let condition = rule(vec![RuleNode::AllowAccounts(BoundedVec::try_from(vec![2]).unwrap())]);
 
 		// Try to add rule with account 2 (should fail)
 		assert_noop!(
-			Sponsorship::add_authorization_rule(RuntimeOrigin::signed(2), 0, rule),
+			Sponsorship::add_authorization_rule(RuntimeOrigin::signed(2), 0, condition),
 			Error::<Test>::NotPoolOwner
 		);
 	});
@@ -335,33 +451,34 @@ fn remove_authorization_rule_works() {
 			daily_spending_limit: 5000,
 			allowed_chains: BoundedVec::try_from(vec![1000]).unwrap(),
 			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		// Create pool
 		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config));
 
 		// Add authorization rule first
-		let rule = AuthorizationRule {
-			rule_type: AuthorizationType::SpendingLimit,
-			data: BoundedVec::try_from(vec![100u8]).unwrap(),
-			created_at: 1,
-		};
-		assert_ok!(Sponsorship::add_authorization_rule(RuntimeOrigin::signed(1), 0, rule));
+		assert_ok!(Sponsorship::add_authorization_rule(
+			RuntimeOrigin::signed(1),
+			0,
+			rule(vec![RuleNode::MaxPerTx(100)])
+		));
 
 		// Remove authorization rule
-		assert_ok!(Sponsorship::remove_authorization_rule(
-			RuntimeOrigin::signed(1), 
-			0, 
-			AuthorizationType::SpendingLimit
-		));
+		assert_ok!(Sponsorship::remove_authorization_rule(RuntimeOrigin::signed(1), 0, 0));
 
 		// Check that rule was removed
-		assert!(Sponsorship::pool_authorizations(0, AuthorizationType::SpendingLimit).is_none());
+		assert!(Sponsorship::pool_authorizations(0, 0).is_none());
+		assert_eq!(Sponsorship::pool_rule_count(0), 0);
 
 		// Check that event was emitted
 		System::assert_last_event(Event::AuthorizationRuleRemoved {
 			pool_id: 0,
-			rule_type: AuthorizationType::SpendingLimit,
+			rule_id: 0,
 		}.into());
 	});
 }
@@ -374,6 +491,11 @@ fn remove_authorization_rule_fails_for_non_owner() {
 			daily_spending_limit: 5000,
 			allowed_chains: BoundedVec::try_from(vec![1000]).unwrap(),
 			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		// Create pool with account 1
@@ -381,7 +503,7 @@ fn remove_authorization_rule_fails_for_non_owner() {
 
 		// Try to remove rule with account 2 (should fail)
 		assert_noop!(
-			Sponsorship::remove_authorization_rule(RuntimeOrigin::signed(2), 0, AuthorizationType::UserWhitelist),
+			Sponsorship::remove_authorization_rule(RuntimeOrigin::signed(2), 0, 0),
 			Error::<Test>::NotPoolOwner
 		);
 	});
@@ -397,7 +519,8 @@ fn sponsor_transaction_fails_for_nonexistent_pool() {
 				RuntimeOrigin::signed(1),
 				999, // Non-existent pool
 				1000,
-				vec![1, 2, 3, 4]
+				vec![1, 2, 3, 4],
+				None
 			),
 			Error::<Test>::PoolNotFound
 		);
@@ -412,6 +535,11 @@ fn sponsor_transaction_fails_for_insufficient_funds() {
 			daily_spending_limit: 5000,
 			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
 			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		// Create pool with minimal balance (need at least 1000 for minimum deposit)
@@ -422,7 +550,8 @@ fn sponsor_transaction_fails_for_insufficient_funds() {
 			RuntimeOrigin::signed(2),
 			0,
 			2006, // Astar
-			vec![1, 2, 3, 4]
+			vec![1, 2, 3, 4],
+			None
 		));
 
 		// Second transaction should fail due to insufficient funds
@@ -431,7 +560,8 @@ fn sponsor_transaction_fails_for_insufficient_funds() {
 				RuntimeOrigin::signed(2),
 				0,
 				2006, // Astar
-				vec![5, 6, 7, 8]
+				vec![5, 6, 7, 8],
+				None
 			),
 			Error::<Test>::InsufficientFunds
 		);
@@ -459,6 +589,11 @@ fn create_pool_with_multiple_chains() {
 			daily_spending_limit: 10000,
 			allowed_chains: BoundedVec::try_from(vec![1000, 2000, 3000, 4000]).unwrap(),
 			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		// Create pool with multiple supported chains
@@ -483,6 +618,11 @@ fn sponsor_transaction_creates_correct_transaction_record() {
 			daily_spending_limit: 5000,
 			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
 			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		// Create pool
@@ -495,7 +635,8 @@ fn sponsor_transaction_creates_correct_transaction_record() {
 			RuntimeOrigin::signed(2),
 			0,
 			2006, // Astar
-			call_data.clone()
+			call_data.clone(),
+			None
 		));
 
 		// Check transaction record
@@ -522,6 +663,11 @@ fn multiple_pools_can_be_created() {
 			daily_spending_limit: 5000,
 			allowed_chains: BoundedVec::try_from(vec![1000]).unwrap(),
 			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		let config2 = PoolConfig {
@@ -529,6 +675,11 @@ fn multiple_pools_can_be_created() {
 			daily_spending_limit: 10000,
 			allowed_chains: BoundedVec::try_from(vec![2000]).unwrap(),
 			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		// Create first pool
@@ -561,6 +712,11 @@ fn pool_balance_updates_correctly_after_funding() {
 			daily_spending_limit: 5000,
 			allowed_chains: BoundedVec::try_from(vec![1000]).unwrap(),
 			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		// Create pool
@@ -577,7 +733,7 @@ fn pool_balance_updates_correctly_after_funding() {
 }
 
 #[test]
-fn authorization_rules_can_be_overwritten() {
+fn multiple_authorization_rules_are_kept() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 
@@ -586,30 +742,3275 @@ fn authorization_rules_can_be_overwritten() {
 			daily_spending_limit: 5000,
 			allowed_chains: BoundedVec::try_from(vec![1000]).unwrap(),
 			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
 		};
 
 		// Create pool
 		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config));
 
 		// Add first rule
-		let rule1 = AuthorizationRule {
-			rule_type: AuthorizationType::UserWhitelist,
-			data: BoundedVec::try_from(vec![2u8]).unwrap(),
-			created_at: 1,
-		};
-		assert_ok!(Sponsorship::add_authorization_rule(RuntimeOrigin::signed(1), 0, rule1));
+		let rule1 = rule(vec![RuleNode::AllowAccounts(BoundedVec::try_from(vec![2]).unwrap())]);
+		assert_ok!(Sponsorship::add_authorization_rule(RuntimeOrigin::signed(1), 0, rule1.clone()));
 
-		// Add second rule with same type (should overwrite)
-		let rule2 = AuthorizationRule {
-			rule_type: AuthorizationType::UserWhitelist,
-			data: BoundedVec::try_from(vec![3u8, 4u8]).unwrap(),
-			created_at: 2,
-		};
+		// Add second rule of the same kind
+		System::set_block_number(2);
+		let rule2 = rule(vec![RuleNode::AllowAccounts(BoundedVec::try_from(vec![3, 4]).unwrap())]);
 		assert_ok!(Sponsorship::add_authorization_rule(RuntimeOrigin::signed(1), 0, rule2.clone()));
 
-		// Check that second rule overwrote the first
-		let stored_rule = Sponsorship::pool_authorizations(0, AuthorizationType::UserWhitelist).unwrap();
-		assert_eq!(stored_rule.data, rule2.data);
+		// Both rules are kept under their own ids
+		assert_eq!(Sponsorship::pool_authorizations(0, 0).unwrap().condition, rule1);
+		let stored_rule = Sponsorship::pool_authorizations(0, 1).unwrap();
+		assert_eq!(stored_rule.condition, rule2);
 		assert_eq!(stored_rule.created_at, 2);
+		assert_eq!(Sponsorship::pool_rule_count(0), 2);
+
+		// Ids are not reused after removal
+		assert_ok!(Sponsorship::remove_authorization_rule(RuntimeOrigin::signed(1), 0, 1));
+		assert_noop!(
+			Sponsorship::remove_authorization_rule(RuntimeOrigin::signed(1), 0, 1),
+			Error::<Test>::RuleNotFound
+		);
+		assert_ok!(Sponsorship::add_authorization_rule(RuntimeOrigin::signed(1), 0, rule2));
+		assert!(Sponsorship::pool_authorizations(0, 2).is_some());
+	});
+}
+
+#[test]
+fn add_authorization_rule_fails_at_max_rules() {
+	new_test_ext().execute_with(|| {
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![1000]).unwrap(),
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config));
+
+		let condition = rule(vec![RuleNode::MaxPerTx(100)]);
+		crate::PoolRuleCount::<Test>::insert(0, 15);
+		assert_ok!(Sponsorship::add_authorization_rule(RuntimeOrigin::signed(1), 0, condition.clone()));
+		assert_noop!(
+			Sponsorship::add_authorization_rule(RuntimeOrigin::signed(1), 0, condition),
+			Error::<Test>::TooManyRules
+		);
+	});
+}
+
+#[test]
+fn create_pool_fails_when_account_has_max_pools() {
+	new_test_ext().execute_with(|| {
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![1000]).unwrap(),
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 2, 1_000_000));
+		for _ in 0..100 {
+			assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(2), 1000, config.clone()));
+		}
+		assert_eq!(Sponsorship::owned_pool_count(2), 100);
+		assert_noop!(
+			Sponsorship::create_pool(RuntimeOrigin::signed(2), 1000, config.clone()),
+			Error::<Test>::TooManyPools
+		);
+
+		// Closing a pool frees a slot
+		assert_ok!(Sponsorship::close_pool(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Sponsorship::owned_pool_count(2), 99);
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(2), 1000, config));
+	});
+}
+// Automatic pool selection tests
+fn candidates(pools: u32) -> BoundedVec<u32, ConstU32<16>> {
+	BoundedVec::try_from((0..pools).collect::<Vec<_>>()).unwrap()
+}
+
+#[test]
+fn sponsor_transaction_auto_picks_eligible_pool() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let acala_config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2000]).unwrap(), // Acala
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+		let astar_config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, acala_config));
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, astar_config));
+
+		// Only pool 1 supports Astar
+		assert_ok!(Sponsorship::sponsor_transaction_auto(
+			RuntimeOrigin::signed(2),
+			2006,
+			vec![1, 2, 3, 4],
+			candidates(2),
+			PoolSelectionPreference::Any
+		));
+
+		let tx = Sponsorship::transaction_log(0).unwrap();
+		assert_eq!(tx.pool_id, 1);
+		assert_eq!(Sponsorship::pools(1).unwrap().balance, 1192);
+		assert_eq!(Sponsorship::pools(0).unwrap().balance, 2000);
+	});
+}
+
+#[test]
+fn sponsor_transaction_auto_falls_back_to_next_pool() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 1000, config.clone()));
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(2), 2000, config));
+
+		// Drain pool 0 below the 808 Astar fee
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_eq!(Sponsorship::pools(0).unwrap().balance, 192);
+
+		// Pool 0 fails the funding check, so pool 1 is used
+		assert_ok!(Sponsorship::sponsor_transaction_auto(
+			RuntimeOrigin::signed(3),
+			2006,
+			vec![5, 6, 7, 8],
+			candidates(2),
+			PoolSelectionPreference::Any
+		));
+
+		let tx = Sponsorship::transaction_log(1).unwrap();
+		assert_eq!(tx.pool_id, 1);
+		assert_eq!(Sponsorship::pools(0).unwrap().balance, 192);
+		assert_eq!(Sponsorship::pools(1).unwrap().balance, 1192);
+		assert_eq!(Sponsorship::next_transaction_id(), 2);
+	});
+}
+
+#[test]
+fn sponsor_transaction_auto_respects_owner_and_tag_preferences() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let tag: BoundedVec<u8, _> = BoundedVec::try_from(b"mint-day".to_vec()).unwrap();
+		let plain_config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+		let tagged_config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: Some(tag.clone()),
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, plain_config));
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(2), 2000, tagged_config));
+
+		assert_ok!(Sponsorship::sponsor_transaction_auto(
+			RuntimeOrigin::signed(3),
+			2006,
+			vec![1, 2, 3, 4],
+			candidates(2),
+			PoolSelectionPreference::CampaignTag(tag)
+		));
+		assert_eq!(Sponsorship::transaction_log(0).unwrap().pool_id, 1);
+
+		assert_ok!(Sponsorship::sponsor_transaction_auto(
+			RuntimeOrigin::signed(3),
+			2006,
+			vec![1, 2, 3, 4],
+			candidates(2),
+			PoolSelectionPreference::Owner(1)
+		));
+		assert_eq!(Sponsorship::transaction_log(1).unwrap().pool_id, 0);
+	});
+}
+
+#[test]
+fn sponsor_transaction_auto_prefers_cheapest_coverage() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config.clone()));
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(2), 1500, config));
+
+		assert_ok!(Sponsorship::sponsor_transaction_auto(
+			RuntimeOrigin::signed(3),
+			2006,
+			vec![1, 2, 3, 4],
+			candidates(2),
+			PoolSelectionPreference::CheapestCoverage
+		));
+		assert_eq!(Sponsorship::transaction_log(0).unwrap().pool_id, 1);
+	});
+}
+
+#[test]
+fn sponsor_transaction_auto_only_considers_candidates() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		for _ in 0..3 {
+			assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config.clone()));
+		}
+
+		// Candidates are tried by pool ID whatever order they are given in
+		assert_ok!(Sponsorship::sponsor_transaction_auto(
+			RuntimeOrigin::signed(2),
+			2006,
+			vec![1, 2, 3, 4],
+			BoundedVec::try_from(vec![2, 1, 2]).unwrap(),
+			PoolSelectionPreference::Any
+		));
+		assert_eq!(Sponsorship::transaction_log(0).unwrap().pool_id, 1);
+
+		assert_noop!(
+			Sponsorship::sponsor_transaction_auto(
+				RuntimeOrigin::signed(2),
+				2006,
+				vec![1, 2, 3, 4],
+				BoundedVec::try_from(vec![7]).unwrap(),
+				PoolSelectionPreference::Any
+			),
+			Error::<Test>::NoEligiblePool
+		);
+	});
+}
+
+#[test]
+fn sponsor_transaction_auto_selects_from_chain_index_without_candidates() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2000, 2006]).unwrap(), // Acala and Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+		let auto = |preference| {
+			Sponsorship::sponsor_transaction_auto(
+				RuntimeOrigin::signed(3),
+				2006,
+				vec![1, 2, 3, 4],
+				BoundedVec::default(),
+				preference,
+			)
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config.clone()));
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(2), 2000, config.clone()));
+		assert!(Sponsorship::chain_pools(2000, 0).is_some());
+		assert!(Sponsorship::chain_pools(2006, 1).is_some());
+
+		assert_ok!(auto(PoolSelectionPreference::Owner(2)));
+		assert_eq!(Sponsorship::transaction_log(0).unwrap().pool_id, 1);
+
+		// Dropping Astar takes pool 1 out of the Astar index
+		let acala_only = PoolConfig { allowed_chains: BoundedVec::try_from(vec![2000]).unwrap(), ..config };
+		assert_ok!(Sponsorship::update_pool_config(RuntimeOrigin::signed(2), 1, acala_only));
+		assert!(Sponsorship::chain_pools(2006, 1).is_none());
+		assert!(Sponsorship::chain_pools(2000, 1).is_some());
+		assert_noop!(auto(PoolSelectionPreference::Owner(2)), Error::<Test>::NoEligiblePool);
+
+		// Closed pools leave the index
+		assert_ok!(Sponsorship::close_pool(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Sponsorship::pools(0).unwrap().status, PoolStatus::Closed);
+		assert!(Sponsorship::chain_pools(2006, 0).is_none());
+		assert!(Sponsorship::chain_pools(2000, 0).is_none());
+		assert_noop!(auto(PoolSelectionPreference::Any), Error::<Test>::NoEligiblePool);
+	});
+}
+
+#[test]
+fn sponsor_transaction_auto_fails_without_eligible_pool() {
+	new_test_ext().execute_with(|| {
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2000]).unwrap(), // Acala
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config));
+
+		assert_noop!(
+			Sponsorship::sponsor_transaction_auto(
+				RuntimeOrigin::signed(2),
+				2006,
+				vec![1, 2, 3, 4],
+				candidates(2),
+				PoolSelectionPreference::Any
+			),
+			Error::<Test>::NoEligiblePool
+		);
+	});
+}
+
+// Gateway error mapping tests
+#[test]
+fn sponsor_transaction_reports_unsupported_destination() {
+	new_test_ext().execute_with(|| {
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![1000]).unwrap(), // Allowed by pool, unknown to gateway
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config));
+
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 1000, vec![1, 2, 3, 4], None),
+			Error::<Test>::UnsupportedDestination
+		);
+	});
+}
+
+#[test]
+fn process_receipt_reports_invalid_receipt() {
+	new_test_ext().execute_with(|| {
+		let receipt = TransactionReceipt::<Test> {
+			transaction_id: 0,
+			target_chain: 2006,
+			execution_result: ExecutionResult::Success,
+			actual_gas_used: 800,
+			block_hash: sp_core::H256::zero(), // Invalid block hash
+			block_number: 1,
+			events: BoundedVec::try_from(vec![]).unwrap(),
+		};
+
+		assert_noop!(
+			Sponsorship::process_receipt(RuntimeOrigin::root(), receipt),
+			Error::<Test>::InvalidReceipt
+		);
+	});
+}
+
+#[test]
+fn process_receipt_requires_receipt_origin() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+
+		// Neither the user nor the pool owner can settle with a made-up receipt
+		for who in [1, 2] {
+			assert_noop!(
+				Sponsorship::process_receipt(
+					RuntimeOrigin::signed(who),
+					receipt(0, 2006, ExecutionResult::Success, 1)
+				),
+				sp_runtime::DispatchError::BadOrigin
+			);
+		}
+		assert_eq!(Sponsorship::pool_in_flight(0), 1);
+		assert_eq!(Sponsorship::pools(0).unwrap().balance, 2000 - 808);
 	});
-}
\ No newline at end of file
+}
+
+// Settlement tests
+fn receipt(
+	transaction_id: u64,
+	target_chain: u32,
+	execution_result: ExecutionResult,
+	actual_gas_used: u128,
+) -> TransactionReceipt<Test> {
+	TransactionReceipt {
+		transaction_id,
+		target_chain,
+		execution_result,
+		actual_gas_used,
+		block_hash: sp_core::H256::from([1; 32]),
+		block_number: 1,
+		events: BoundedVec::try_from(vec![]).unwrap(),
+	}
+}
+
+#[test]
+fn process_receipt_settles_and_refunds_unused_fee() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+
+		assert_ok!(Sponsorship::process_receipt(RuntimeOrigin::root(), receipt(1, 2006, ExecutionResult::Success, 500)));
+
+		// 808 reserved, 500 used, 308 returned to the pool
+		let pool = Sponsorship::pools(0).unwrap();
+		assert_eq!(pool.balance, 5000 - 808 - 500);
+		assert_eq!(pool.total_spent, 808 + 500);
+
+		let record = Sponsorship::transaction_log(1).unwrap();
+		assert_eq!(record.status, TransactionStatus::Executed);
+		assert_eq!(record.gas_cost, 500);
+		assert_eq!(Sponsorship::user_spending(0, 2).total_spent, 500);
+
+		System::assert_last_event(
+			Event::TransactionSettled {
+				transaction_id: 1,
+				pool_id: 0,
+				status: TransactionStatus::Executed,
+				actual_cost: 500,
+				refund: 308,
+			}
+			.into(),
+		);
+
+		// A transaction settles only once
+		assert_noop!(
+			Sponsorship::process_receipt(RuntimeOrigin::root(), receipt(1, 2006, ExecutionResult::Success, 500)),
+			Error::<Test>::TransactionAlreadySettled
+		);
+	});
+}
+
+#[test]
+fn process_receipt_refunds_undelivered_transaction() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+
+		assert_ok!(Sponsorship::process_receipt(RuntimeOrigin::root(), receipt(1, 2006, ExecutionResult::Timeout, 0)));
+
+		let pool = Sponsorship::pools(0).unwrap();
+		assert_eq!(pool.balance, 5000 - 808);
+		assert_eq!(pool.total_spent, 808);
+		assert_eq!(Sponsorship::transaction_log(1).unwrap().status, TransactionStatus::Refunded);
+
+		let snapshot = Sponsorship::pool_daily_stats(0, 0);
+		assert_eq!(snapshot.sponsored_count, 2);
+		assert_eq!(snapshot.refunded_count, 1);
+		assert_eq!(snapshot.total_spent, 0);
+		assert_eq!(snapshot.new_user_count, 0);
+	});
+}
+
+#[test]
+fn process_receipt_fails_for_unknown_transaction() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Sponsorship::process_receipt(RuntimeOrigin::root(), receipt(7, 2006, ExecutionResult::Success, 500)),
+			Error::<Test>::TransactionNotFound
+		);
+	});
+}
+
+// Low-balance alert tests
+#[test]
+fn pool_balance_low_is_emitted_once_per_crossing() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 2000,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		let low_balance_alerts = || {
+			System::events()
+				.iter()
+				.filter(|record| {
+					matches!(record.event, RuntimeEvent::Sponsorship(Event::PoolBalanceLow { .. }))
+				})
+				.count()
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 3000, config));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_eq!(low_balance_alerts(), 0);
+
+		// 2192 -> 1384 crosses the threshold
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		System::assert_has_event(
+			Event::PoolBalanceLow { pool_id: 0, balance: 1384, threshold: 2000 }.into(),
+		);
+		assert!(Sponsorship::low_balance_alerted(0));
+
+		// Further spend below the threshold does not repeat the alert
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_eq!(low_balance_alerts(), 1);
+
+		// Recovering to just above the threshold stays inside the hysteresis band
+		assert_ok!(Sponsorship::fund_pool(RuntimeOrigin::signed(1), 0, 1500));
+		assert_eq!(Sponsorship::pools(0).unwrap().balance, 2076);
+		assert!(Sponsorship::low_balance_alerted(0));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_eq!(low_balance_alerts(), 1);
+
+		// Clearing threshold + 10% re-arms the alert for the next crossing
+		assert_ok!(Sponsorship::fund_pool(RuntimeOrigin::signed(1), 0, 1000));
+		assert_eq!(Sponsorship::pools(0).unwrap().balance, 2268);
+		assert!(!Sponsorship::low_balance_alerted(0));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_eq!(low_balance_alerts(), 2);
+	});
+}
+
+// Auto top-up tests
+#[test]
+fn auto_top_up_refills_pool_within_caps() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+		let rule = AutoTopUpRule { trigger_balance: 1500, amount: 1000, daily_cap: 1500, lifetime_cap: 2500 };
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config));
+		assert_ok!(Sponsorship::set_auto_top_up(RuntimeOrigin::signed(1), 0, rule));
+		assert!(Sponsorship::pending_top_ups().is_empty());
+
+		// 2000 -> 1192 queues the pool, the next block refills it
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_eq!(Sponsorship::pending_top_ups().into_inner(), vec![0]);
+		Sponsorship::on_initialize(2);
+		assert!(Sponsorship::pending_top_ups().is_empty());
+		assert_eq!(Sponsorship::pools(0).unwrap().balance, 2192);
+		assert_eq!(Balances::reserved_balance(1), 3000);
+		System::assert_last_event(Event::PoolAutoToppedUp { pool_id: 0, amount: 1000, new_balance: 2192 }.into());
+
+		// Second top-up of the day is limited by the daily cap
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		Sponsorship::on_initialize(3);
+		assert_eq!(Sponsorship::pools(0).unwrap().balance, 1384 + 500);
+
+		// Daily cap exhausted
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		Sponsorship::on_initialize(4);
+		assert_eq!(Sponsorship::pools(0).unwrap().balance, 1076);
+
+		// Next day tops up again, up to the lifetime cap
+		System::set_block_number(150);
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		Sponsorship::on_initialize(150);
+		assert_eq!(Sponsorship::pools(0).unwrap().balance, 268 + 1000);
+		assert_eq!(Sponsorship::auto_top_ups(0).unwrap().topped_up_total, 2500);
+
+		// Lifetime cap exhausted
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		Sponsorship::on_initialize(151);
+		assert_eq!(Sponsorship::pools(0).unwrap().balance, 460);
+	});
+}
+
+#[test]
+fn auto_top_up_skips_when_owner_cannot_pay() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+		let rule = AutoTopUpRule { trigger_balance: 9000, amount: 5000, daily_cap: 5000, lifetime_cap: 5000 };
+
+		// Owner keeps only 1000 free
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 9000, config));
+		assert_ok!(Sponsorship::set_auto_top_up(RuntimeOrigin::signed(1), 0, rule));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+
+		Sponsorship::on_initialize(2);
+		assert!(Sponsorship::pending_top_ups().is_empty());
+		assert_eq!(Sponsorship::pools(0).unwrap().balance, 9000 - 808);
+		assert_eq!(Sponsorship::auto_top_ups(0).unwrap().topped_up_total, 0);
+	});
+}
+
+#[test]
+fn set_auto_top_up_checks_owner_and_rule() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+		let rule = AutoTopUpRule { trigger_balance: 1500, amount: 1000, daily_cap: 1500, lifetime_cap: 2500 };
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config));
+
+		assert_noop!(
+			Sponsorship::set_auto_top_up(RuntimeOrigin::signed(2), 0, rule.clone()),
+			Error::<Test>::NotPoolOwner
+		);
+		assert_noop!(
+			Sponsorship::set_auto_top_up(RuntimeOrigin::signed(1), 0, AutoTopUpRule { daily_cap: 500, ..rule.clone() }),
+			Error::<Test>::InvalidAutoTopUpRule
+		);
+		assert_noop!(
+			Sponsorship::clear_auto_top_up(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::AutoTopUpNotFound
+		);
+
+		assert_ok!(Sponsorship::set_auto_top_up(RuntimeOrigin::signed(1), 0, rule));
+		assert_ok!(Sponsorship::clear_auto_top_up(RuntimeOrigin::signed(1), 0));
+		assert!(Sponsorship::auto_top_ups(0).is_none());
+		System::assert_last_event(Event::AutoTopUpCleared { pool_id: 0 }.into());
+	});
+}
+
+// Pool closing tests
+#[test]
+fn close_pool_refunds_contributors_pro_rata() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 3000, config));
+		assert_ok!(Sponsorship::fund_pool(RuntimeOrigin::signed(2), 0, 1000));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_ok!(Sponsorship::process_receipt(RuntimeOrigin::root(), receipt(0, 2006, ExecutionResult::Success, 808)));
+		assert_ok!(Sponsorship::process_receipt(RuntimeOrigin::root(), receipt(1, 2006, ExecutionResult::Success, 808)));
+
+		let issuance = Balances::total_issuance();
+		assert_noop!(Sponsorship::close_pool(RuntimeOrigin::signed(2), 0), Error::<Test>::NotPoolOwner);
+		assert_ok!(Sponsorship::close_pool(RuntimeOrigin::signed(1), 0));
+
+		// 2384 left, split 3:1
+		System::assert_has_event(Event::ContributionRefunded { pool_id: 0, contributor: 1, amount: 1788 }.into());
+		System::assert_has_event(Event::ContributionRefunded { pool_id: 0, contributor: 2, amount: 596 }.into());
+		System::assert_last_event(Event::PoolClosed { pool_id: 0, refunded: 2384 }.into());
+
+		assert_eq!(Balances::free_balance(1), 10000 - 3000 + 1788);
+		assert_eq!(Balances::free_balance(2), 20000 - 1000 + 596);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		// The spent share is consumed
+		assert_eq!(Balances::total_issuance(), issuance - 808 * 2);
+
+		let pool = Sponsorship::pools(0).unwrap();
+		assert_eq!(pool.status, PoolStatus::Closed);
+		assert_eq!(pool.balance, 0);
+		assert_eq!(Sponsorship::pool_contributors(0, 2), 0);
+
+		assert_noop!(Sponsorship::close_pool(RuntimeOrigin::signed(1), 0), Error::<Test>::PoolClosed);
+	});
+}
+
+#[test]
+fn close_pool_waits_for_in_flight_transactions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 3000, config));
+		assert_ok!(Sponsorship::fund_pool(RuntimeOrigin::signed(2), 0, 1000));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2006, vec![1, 2, 3, 4], None));
+
+		// Transaction 0 is in flight, so the pool only pauses
+		assert_ok!(Sponsorship::close_pool(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(Event::PoolClosureScheduled { pool_id: 0, in_flight: 1 }.into());
+		assert_eq!(Sponsorship::pools(0).unwrap().status, PoolStatus::Paused);
+		assert_eq!(Balances::reserved_balance(1), 3000);
+
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2006, vec![1, 2, 3, 4], None),
+			Error::<Test>::PoolPaused
+		);
+		assert_noop!(Sponsorship::resume_pool(RuntimeOrigin::signed(1), 0), Error::<Test>::PoolClosing);
+		assert_noop!(Sponsorship::close_pool(RuntimeOrigin::signed(1), 0), Error::<Test>::PoolClosing);
+
+		// Settling refunds 308 to the pool, which then closes and splits 3500 3:1
+		let issuance = Balances::total_issuance();
+		assert_ok!(Sponsorship::process_receipt(RuntimeOrigin::root(), receipt(0, 2006, ExecutionResult::Success, 500)));
+		System::assert_has_event(Event::ContributionRefunded { pool_id: 0, contributor: 1, amount: 2625 }.into());
+		System::assert_has_event(Event::ContributionRefunded { pool_id: 0, contributor: 2, amount: 875 }.into());
+		System::assert_last_event(Event::PoolClosed { pool_id: 0, refunded: 3500 }.into());
+
+		assert_eq!(Sponsorship::pools(0).unwrap().status, PoolStatus::Closed);
+		assert!(Sponsorship::pending_closure(0).is_none());
+		assert_eq!(Balances::free_balance(1), 10000 - 3000 + 2625);
+		assert_eq!(Balances::free_balance(2), 20000 - 1000 + 875);
+		assert_eq!(Balances::total_issuance(), issuance - 500);
+	});
+}
+
+// Pool expiry tests
+#[test]
+fn expired_pool_closes_and_refunds_at_expiry_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: Some(10),
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config));
+		assert_ok!(Sponsorship::fund_pool(RuntimeOrigin::signed(2), 0, 1000));
+		assert_eq!(Sponsorship::expiry_queue(10).into_inner(), vec![0]);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2006, vec![1, 2, 3, 4], None),
+			Error::<Test>::PoolExpired
+		);
+
+		Sponsorship::on_initialize(10);
+		System::assert_last_event(Event::PoolExpired { pool_id: 0, refunded: 3000 }.into());
+		assert_eq!(Sponsorship::pools(0).unwrap().status, PoolStatus::Closed);
+		assert_eq!(Balances::free_balance(1), 10000);
+		assert_eq!(Balances::free_balance(2), 20000);
+	});
+}
+
+#[test]
+fn expiring_pool_waits_for_in_flight_transactions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(
+			RuntimeOrigin::signed(1),
+			3000,
+			PoolConfig { expires_at: Some(10), ..config }
+		));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_eq!(Sponsorship::pool_in_flight(0), 1);
+
+		// Transaction 0 is still in flight, so the pool stays open
+		System::set_block_number(10);
+		Sponsorship::on_initialize(10);
+		assert_eq!(Sponsorship::pools(0).unwrap().status, PoolStatus::Active);
+
+		// Settling it refunds 308 and closes the pool
+		assert_ok!(Sponsorship::process_receipt(RuntimeOrigin::root(), receipt(0, 2006, ExecutionResult::Success, 500)));
+		System::assert_last_event(Event::PoolExpired { pool_id: 0, refunded: 2500 }.into());
+		assert_eq!(Sponsorship::pools(0).unwrap().status, PoolStatus::Closed);
+		assert_eq!(Sponsorship::pool_in_flight(0), 0);
+		assert_eq!(Balances::free_balance(1), 10000 - 500);
+	});
+}
+
+#[test]
+fn create_pool_rejects_past_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: Some(5),
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_noop!(
+			Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config),
+			Error::<Test>::InvalidExpiry
+		);
+	});
+}
+
+// Sponsorship schedule tests
+#[test]
+fn sponsorship_respects_block_windows() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let schedule = SponsorshipSchedule {
+			block_ranges: BoundedVec::try_from(vec![(10, 20)]).unwrap(),
+			time_ranges: BoundedVec::default(),
+			weekly: BoundedVec::default(),
+		};
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: Some(schedule),
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+
+		System::set_block_number(5);
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None),
+			Error::<Test>::OutsideSponsorshipWindow
+		);
+		assert_eq!(
+			Sponsorship::next_sponsorship_window(0),
+			Some(SponsorshipWindow::OpensAt { block: Some(10), timestamp: None })
+		);
+
+		System::set_block_number(10);
+		assert_eq!(Sponsorship::next_sponsorship_window(0), Some(SponsorshipWindow::Open));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+
+		System::set_block_number(20);
+		assert_eq!(Sponsorship::next_sponsorship_window(0), Some(SponsorshipWindow::Closed));
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None),
+			Error::<Test>::OutsideSponsorshipWindow
+		);
+	});
+}
+
+#[test]
+fn sponsorship_respects_weekly_windows_within_time_ranges() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// 1970-01-05 00:00 UTC, a Monday
+		const MONDAY: u64 = 4 * 24 * 3_600_000;
+		const HOUR: u64 = 3_600_000;
+
+		// Mondays 09:00-17:00, during the first ten days of 1970
+		let schedule = SponsorshipSchedule {
+			block_ranges: BoundedVec::default(),
+			time_ranges: BoundedVec::try_from(vec![(0, 10 * 24 * HOUR)]).unwrap(),
+			weekly: BoundedVec::try_from(vec![WeeklyWindow { day: 0, start_minute: 540, end_minute: 1020 }])
+				.unwrap(),
+		};
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: Some(schedule),
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+
+		Now::set(MONDAY + 8 * HOUR);
+		assert_eq!(
+			Sponsorship::next_sponsorship_window(0),
+			Some(SponsorshipWindow::OpensAt { block: None, timestamp: Some(MONDAY + 9 * HOUR) })
+		);
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None),
+			Error::<Test>::OutsideSponsorshipWindow
+		);
+
+		Now::set(MONDAY + 9 * HOUR);
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+
+		// The next Monday falls after the last time range
+		Now::set(MONDAY + 17 * HOUR);
+		assert_eq!(Sponsorship::next_sponsorship_window(0), Some(SponsorshipWindow::Closed));
+	});
+}
+
+#[test]
+fn create_pool_rejects_invalid_schedule() {
+	new_test_ext().execute_with(|| {
+		let schedule = SponsorshipSchedule {
+			block_ranges: BoundedVec::default(),
+			time_ranges: BoundedVec::default(),
+			weekly: BoundedVec::try_from(vec![WeeklyWindow { day: 2, start_minute: 600, end_minute: 1500 }])
+				.unwrap(),
+		};
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: Some(schedule),
+			config_update_delay: 0,
+		};
+
+		assert_noop!(
+			Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config),
+			Error::<Test>::InvalidSchedule
+		);
+	});
+}
+
+// Pool role tests
+#[test]
+fn owner_grants_and_revokes_roles() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config));
+
+		assert_noop!(
+			Sponsorship::grant_pool_role(RuntimeOrigin::signed(2), 0, 2, PoolRole::Admin),
+			Error::<Test>::NotPoolOwner
+		);
+		assert_ok!(Sponsorship::grant_pool_role(RuntimeOrigin::signed(1), 0, 2, PoolRole::Admin));
+		System::assert_last_event(Event::PoolRoleGranted { pool_id: 0, who: 2, role: PoolRole::Admin }.into());
+
+		// Admins cannot hand out roles themselves
+		assert_noop!(
+			Sponsorship::grant_pool_role(RuntimeOrigin::signed(2), 0, 3, PoolRole::Operator),
+			Error::<Test>::NotPoolOwner
+		);
+
+		// Granting again replaces the role
+		assert_ok!(Sponsorship::grant_pool_role(RuntimeOrigin::signed(1), 0, 2, PoolRole::Auditor));
+		assert_eq!(Sponsorship::pool_roles(0, 2), Some(PoolRole::Auditor));
+
+		assert_ok!(Sponsorship::revoke_pool_role(RuntimeOrigin::signed(1), 0, 2));
+		System::assert_last_event(Event::PoolRoleRevoked { pool_id: 0, who: 2 }.into());
+		assert!(Sponsorship::pool_roles(0, 2).is_none());
+		assert_noop!(
+			Sponsorship::revoke_pool_role(RuntimeOrigin::signed(1), 0, 2),
+			Error::<Test>::PoolRoleNotFound
+		);
+	});
+}
+
+#[test]
+fn admin_manages_config_and_rules() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config.clone()));
+		assert_ok!(Sponsorship::grant_pool_role(RuntimeOrigin::signed(1), 0, 2, PoolRole::Admin));
+
+		let new_config = PoolConfig { max_transaction_value: 500, ..config };
+		assert_ok!(Sponsorship::update_pool_config(RuntimeOrigin::signed(2), 0, new_config));
+		assert_eq!(Sponsorship::pools(0).unwrap().config.max_transaction_value, 500);
+
+		let condition = rule(vec![RuleNode::AllowAccounts(BoundedVec::try_from(vec![3]).unwrap())]);
+		assert_ok!(Sponsorship::add_authorization_rule(RuntimeOrigin::signed(2), 0, condition));
+		assert_ok!(Sponsorship::remove_authorization_rule(RuntimeOrigin::signed(2), 0, 0));
+
+		assert_ok!(Sponsorship::pause_pool(RuntimeOrigin::signed(2), 0));
+
+		// Closing stays with the owner
+		assert_noop!(Sponsorship::close_pool(RuntimeOrigin::signed(2), 0), Error::<Test>::NotPoolOwner);
+	});
+}
+
+#[test]
+fn operator_pauses_and_resumes_but_cannot_change_config() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config.clone()));
+		assert_ok!(Sponsorship::grant_pool_role(RuntimeOrigin::signed(1), 0, 2, PoolRole::Operator));
+
+		assert_ok!(Sponsorship::pause_pool(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(Event::PoolPaused { pool_id: 0, by: 2 }.into());
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2006, vec![1, 2, 3, 4], None),
+			Error::<Test>::PoolPaused
+		);
+		assert_noop!(Sponsorship::pause_pool(RuntimeOrigin::signed(2), 0), Error::<Test>::PoolPaused);
+
+		assert_ok!(Sponsorship::resume_pool(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(Event::PoolResumed { pool_id: 0, by: 2 }.into());
+		assert_noop!(Sponsorship::resume_pool(RuntimeOrigin::signed(2), 0), Error::<Test>::PoolNotPaused);
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2006, vec![1, 2, 3, 4], None));
+
+		let rule = AutoTopUpRule { trigger_balance: 1500, amount: 1000, daily_cap: 1500, lifetime_cap: 2500 };
+		assert_ok!(Sponsorship::set_auto_top_up(RuntimeOrigin::signed(2), 0, rule));
+		assert_ok!(Sponsorship::clear_auto_top_up(RuntimeOrigin::signed(2), 0));
+
+		assert_noop!(
+			Sponsorship::update_pool_config(RuntimeOrigin::signed(2), 0, config),
+			Error::<Test>::InsufficientPoolRole
+		);
+	});
+}
+
+#[test]
+fn auditor_cannot_manage_pool() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config.clone()));
+		assert_ok!(Sponsorship::grant_pool_role(RuntimeOrigin::signed(1), 0, 2, PoolRole::Auditor));
+
+		assert_noop!(Sponsorship::pause_pool(RuntimeOrigin::signed(2), 0), Error::<Test>::InsufficientPoolRole);
+		assert_noop!(
+			Sponsorship::update_pool_config(RuntimeOrigin::signed(2), 0, config),
+			Error::<Test>::InsufficientPoolRole
+		);
+		// Accounts without a role are rejected as before
+		assert_noop!(Sponsorship::pause_pool(RuntimeOrigin::signed(3), 0), Error::<Test>::NotPoolOwner);
+	});
+}
+
+// Ownership transfer tests
+#[test]
+fn pool_ownership_moves_with_deposit_after_acceptance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config.clone()));
+		assert_ok!(Sponsorship::fund_pool(RuntimeOrigin::signed(3), 0, 1000));
+
+		assert_noop!(
+			Sponsorship::propose_pool_owner(RuntimeOrigin::signed(2), 0, 2),
+			Error::<Test>::NotPoolOwner
+		);
+		assert_noop!(
+			Sponsorship::propose_pool_owner(RuntimeOrigin::signed(1), 0, 1),
+			Error::<Test>::AlreadyPoolOwner
+		);
+
+		assert_ok!(Sponsorship::propose_pool_owner(RuntimeOrigin::signed(1), 0, 2));
+		System::assert_last_event(Event::PoolOwnershipProposed { pool_id: 0, owner: 1, proposed: 2 }.into());
+		// Nothing changes until the proposed owner accepts
+		assert_eq!(Sponsorship::pools(0).unwrap().owner, 1);
+		assert_noop!(
+			Sponsorship::accept_pool_ownership(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::NotProposedOwner
+		);
+
+		assert_ok!(Sponsorship::accept_pool_ownership(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(
+			Event::PoolOwnershipTransferred { pool_id: 0, old_owner: 1, new_owner: 2, deposit: 2000 }.into(),
+		);
+
+		assert_eq!(Sponsorship::pools(0).unwrap().owner, 2);
+		assert!(Sponsorship::pool_owners(1, 0).is_none());
+		assert!(Sponsorship::pool_owners(2, 0).is_some());
+		assert_eq!(Sponsorship::owned_pool_count(1), 0);
+		assert_eq!(Sponsorship::owned_pool_count(2), 1);
+		assert!(Sponsorship::pending_pool_owner(0).is_none());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 8000);
+		assert_eq!(Balances::reserved_balance(2), 2000);
+		assert_eq!(Sponsorship::pool_contributors(0, 2), 2000);
+		assert_eq!(Sponsorship::pool_contributions(0).contributors, 2);
+
+		assert_noop!(
+			Sponsorship::update_pool_config(RuntimeOrigin::signed(1), 0, config),
+			Error::<Test>::NotPoolOwner
+		);
+
+		// The new owner gets the old owner's share back on close
+		assert_ok!(Sponsorship::close_pool(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 20000);
+		assert_eq!(Balances::free_balance(3), 30000);
+	});
+}
+
+#[test]
+fn accept_pool_ownership_respects_max_pools_per_account() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config.clone()));
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 2, 1_000_000));
+		for _ in 0..100 {
+			assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(2), 1000, config.clone()));
+		}
+
+		assert_ok!(Sponsorship::propose_pool_owner(RuntimeOrigin::signed(1), 0, 2));
+		assert_noop!(
+			Sponsorship::accept_pool_ownership(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::TooManyPools
+		);
+	});
+}
+
+// Config timelock tests
+#[test]
+fn timelocked_config_update_applies_after_delay() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 10,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config.clone()));
+
+		let new_config = PoolConfig {
+			max_transaction_value: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006, 2000]).unwrap(),
+			..config
+		};
+		assert_ok!(Sponsorship::update_pool_config(RuntimeOrigin::signed(1), 0, new_config.clone()));
+		System::assert_last_event(Event::PoolConfigUpdateScheduled { pool_id: 0, by: 1, apply_at: 11 }.into());
+
+		// The old config stays in force during the delay
+		assert_eq!(Sponsorship::pools(0).unwrap().config.max_transaction_value, 1000);
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2000, vec![1, 2, 3, 4], None),
+			Error::<Test>::ChainNotSupported
+		);
+
+		System::set_block_number(10);
+		Sponsorship::on_initialize(10);
+		assert!(Sponsorship::pending_config_update(0).is_some());
+
+		System::set_block_number(11);
+		Sponsorship::on_initialize(11);
+		System::assert_last_event(Event::PoolConfigUpdateApplied { pool_id: 0 }.into());
+		assert_eq!(Sponsorship::pools(0).unwrap().config, new_config);
+		assert!(Sponsorship::pending_config_update(0).is_none());
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2000, vec![1, 2, 3, 4], None));
+	});
+}
+
+#[test]
+fn timelocked_config_update_can_be_cancelled() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 10,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config.clone()));
+		assert_ok!(Sponsorship::grant_pool_role(RuntimeOrigin::signed(1), 0, 2, PoolRole::Admin));
+
+		assert_noop!(
+			Sponsorship::cancel_pool_config_update(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::NoPendingConfigUpdate
+		);
+
+		let new_config = PoolConfig { max_transaction_value: 5000, ..config.clone() };
+		assert_ok!(Sponsorship::update_pool_config(RuntimeOrigin::signed(1), 0, new_config));
+
+		assert_noop!(
+			Sponsorship::cancel_pool_config_update(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::NotPoolOwner
+		);
+		assert_ok!(Sponsorship::cancel_pool_config_update(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(Event::PoolConfigUpdateCancelled { pool_id: 0, by: 2 }.into());
+
+		System::set_block_number(11);
+		Sponsorship::on_initialize(11);
+		assert_eq!(Sponsorship::pools(0).unwrap().config, config);
+	});
+}
+
+#[test]
+fn newer_config_update_replaces_pending_one() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 10,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config.clone()));
+
+		assert_ok!(Sponsorship::update_pool_config(
+			RuntimeOrigin::signed(1),
+			0,
+			PoolConfig { max_transaction_value: 5000, ..config.clone() }
+		));
+		System::set_block_number(5);
+		assert_ok!(Sponsorship::update_pool_config(
+			RuntimeOrigin::signed(1),
+			0,
+			PoolConfig { max_transaction_value: 500, ..config.clone() }
+		));
+
+		// The first update's slot is skipped
+		System::set_block_number(11);
+		Sponsorship::on_initialize(11);
+		assert_eq!(Sponsorship::pools(0).unwrap().config.max_transaction_value, 1000);
+
+		System::set_block_number(15);
+		Sponsorship::on_initialize(15);
+		assert_eq!(Sponsorship::pools(0).unwrap().config.max_transaction_value, 500);
+
+		// Expiry must fall after the update applies
+		assert_noop!(
+			Sponsorship::update_pool_config(
+				RuntimeOrigin::signed(1),
+				0,
+				PoolConfig { expires_at: Some(20), ..config.clone() }
+			),
+			Error::<Test>::InvalidExpiry
+		);
+	});
+}
+
+#[test]
+fn config_without_delay_applies_immediately() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config.clone()));
+
+		// Adding a delay is itself immediate, later changes wait for it
+		assert_ok!(Sponsorship::update_pool_config(
+			RuntimeOrigin::signed(1),
+			0,
+			PoolConfig { config_update_delay: 5, ..config.clone() }
+		));
+		System::assert_last_event(Event::PoolConfigUpdated { pool_id: 0, owner: 1 }.into());
+		assert_eq!(Sponsorship::pools(0).unwrap().config.config_update_delay, 5);
+
+		assert_ok!(Sponsorship::update_pool_config(RuntimeOrigin::signed(1), 0, config));
+		System::assert_last_event(Event::PoolConfigUpdateScheduled { pool_id: 0, by: 1, apply_at: 6 }.into());
+	});
+}
+
+// Rule evaluation tests
+#[test]
+fn add_authorization_rule_validates_shape_and_depth() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config));
+
+		let add = |nodes| Sponsorship::add_authorization_rule(RuntimeOrigin::signed(1), 0, rule(nodes));
+		assert_noop!(add(vec![]), Error::<Test>::InvalidRule);
+		// Combinator missing a child
+		assert_noop!(add(vec![RuleNode::All(2), RuleNode::MaxPerTx(1)]), Error::<Test>::InvalidRule);
+		// Two conditions without a combinator
+		assert_noop!(add(vec![RuleNode::MaxPerTx(1), RuleNode::MaxPerTx(2)]), Error::<Test>::InvalidRule);
+		assert_noop!(add(vec![RuleNode::Any(0)]), Error::<Test>::InvalidRule);
+		assert_noop!(add(vec![RuleNode::Window { start: 5, end: 5 }]), Error::<Test>::InvalidRule);
+		assert_noop!(
+			add(vec![RuleNode::Not, RuleNode::Not, RuleNode::Not, RuleNode::Not, RuleNode::MaxPerTx(1)]),
+			Error::<Test>::RuleTooDeep
+		);
+
+		assert_ok!(add(vec![RuleNode::Not, RuleNode::Not, RuleNode::Not, RuleNode::MaxPerTx(1)]));
+	});
+}
+
+#[test]
+fn sponsorship_requires_every_rule_to_pass() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+
+		// Requiring authorization without rules sponsors nobody
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None),
+			Error::<Test>::UnauthorizedUser
+		);
+
+		// Account 2 is allowed outright; others need a large balance and a specific call
+		assert_ok!(Sponsorship::add_authorization_rule(
+			RuntimeOrigin::signed(1),
+			0,
+			rule(vec![
+				RuleNode::Any(2),
+				RuleNode::AllowAccounts(BoundedVec::try_from(vec![2]).unwrap()),
+				RuleNode::All(2),
+				RuleNode::MinBalance(25000),
+				RuleNode::CallIndex(BoundedVec::try_from(vec![(1, 2)]).unwrap()),
+			])
+		));
+
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![9, 9, 9, 9], None));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2006, vec![9, 9, 9, 9], None),
+			Error::<Test>::UnauthorizedUser
+		);
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(1), 0, 2006, vec![1, 2, 3, 4], None),
+			Error::<Test>::UnauthorizedUser
+		);
+
+		// A second rule must hold as well
+		assert_ok!(Sponsorship::add_authorization_rule(
+			RuntimeOrigin::signed(1),
+			0,
+			rule(vec![RuleNode::Not, RuleNode::DenyAccounts(BoundedVec::try_from(vec![3]).unwrap())])
+		));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None),
+			Error::<Test>::UnauthorizedUser
+		);
+	});
+}
+
+#[test]
+fn spending_and_window_rules_are_enforced() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+
+		// Each 4-byte Astar call is estimated at 808
+		assert_ok!(Sponsorship::add_authorization_rule(
+			RuntimeOrigin::signed(1),
+			0,
+			rule(vec![
+				RuleNode::All(3),
+				RuleNode::MaxPerTx(900),
+				RuleNode::MaxPerDay(1700),
+				RuleNode::Window { start: 1, end: 150 },
+			])
+		));
+
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_eq!(Sponsorship::spent_today(0, &2), 1616);
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None),
+			Error::<Test>::UnauthorizedUser
+		);
+		// Too expensive for a single transaction
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2006, vec![0; 60], None),
+			Error::<Test>::UnauthorizedUser
+		);
+
+		// The daily allowance resets on the next day
+		System::set_block_number(100);
+		assert_eq!(Sponsorship::spent_today(0, &2), 0);
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+
+		// Outside the window
+		System::set_block_number(150);
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2006, vec![1, 2, 3, 4], None),
+			Error::<Test>::UnauthorizedUser
+		);
+	});
+}
+
+#[test]
+fn sponsor_transaction_auto_skips_pools_that_reject_caller() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config.clone()));
+		assert_ok!(Sponsorship::add_authorization_rule(
+			RuntimeOrigin::signed(1),
+			0,
+			rule(vec![RuleNode::AllowAccounts(BoundedVec::try_from(vec![2]).unwrap())])
+		));
+		assert_ok!(Sponsorship::create_pool(
+			RuntimeOrigin::signed(2),
+			5000,
+			PoolConfig { authorization_required: false, ..config }
+		));
+
+		assert_ok!(Sponsorship::sponsor_transaction_auto(
+			RuntimeOrigin::signed(3),
+			2006,
+			vec![1, 2, 3, 4],
+			candidates(2),
+			PoolSelectionPreference::Any
+		));
+		assert_eq!(Sponsorship::pools(0).unwrap().balance, 5000);
+		assert_eq!(Sponsorship::pools(1).unwrap().balance, 5000 - 808);
+	});
+}
+
+// Whitelist tests
+fn accounts(list: Vec<u64>) -> BoundedVec<u64, ConstU32<16>> {
+	BoundedVec::try_from(list).unwrap()
+}
+
+#[test]
+fn whitelist_entries_reserve_deposit_from_owner() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+		assert_ok!(Sponsorship::grant_pool_role(RuntimeOrigin::signed(1), 0, 2, PoolRole::Admin));
+
+		assert_noop!(
+			Sponsorship::add_to_whitelist(RuntimeOrigin::signed(3), 0, accounts(vec![3])),
+			Error::<Test>::NotPoolOwner
+		);
+
+		// Duplicates and existing entries are not charged twice; an admin charges the owner
+		assert_ok!(Sponsorship::add_to_whitelist(RuntimeOrigin::signed(1), 0, accounts(vec![4, 5, 5])));
+		assert_ok!(Sponsorship::add_to_whitelist(RuntimeOrigin::signed(2), 0, accounts(vec![5, 6])));
+		System::assert_last_event(Event::WhitelistExtended { pool_id: 0, added: 1, deposit: 10 }.into());
+
+		assert_eq!(Sponsorship::pool_whitelist_count(0), 3);
+		assert_eq!(Sponsorship::pool_whitelist_deposit(0), 30);
+		assert_eq!(Balances::reserved_balance(1), 5030);
+		assert_eq!(Balances::reserved_balance(2), 0);
+
+		assert_ok!(Sponsorship::remove_from_whitelist(RuntimeOrigin::signed(1), 0, accounts(vec![4, 7])));
+		System::assert_last_event(Event::WhitelistReduced { pool_id: 0, removed: 1, refunded: 10 }.into());
+		assert_eq!(Sponsorship::pool_whitelist_count(0), 2);
+		assert_eq!(Balances::reserved_balance(1), 5020);
+		assert!(Sponsorship::pool_whitelist(0, 4).is_none());
+
+		// Closed pools can't grow their whitelist but still return deposits
+		assert_ok!(Sponsorship::close_pool(RuntimeOrigin::signed(1), 0));
+		assert_noop!(
+			Sponsorship::add_to_whitelist(RuntimeOrigin::signed(1), 0, accounts(vec![7])),
+			Error::<Test>::PoolClosed
+		);
+		assert_ok!(Sponsorship::remove_from_whitelist(RuntimeOrigin::signed(1), 0, accounts(vec![5, 6])));
+		assert_eq!(Sponsorship::pool_whitelist_count(0), 0);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn whitelisted_rule_checks_stored_whitelist() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+		assert_ok!(Sponsorship::add_authorization_rule(
+			RuntimeOrigin::signed(1),
+			0,
+			rule(vec![RuleNode::Whitelisted])
+		));
+		assert_ok!(Sponsorship::add_to_whitelist(RuntimeOrigin::signed(1), 0, accounts(vec![2])));
+
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2006, vec![1, 2, 3, 4], None),
+			Error::<Test>::UnauthorizedUser
+		);
+
+		assert_ok!(Sponsorship::remove_from_whitelist(RuntimeOrigin::signed(1), 0, accounts(vec![2])));
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None),
+			Error::<Test>::UnauthorizedUser
+		);
+	});
+}
+
+#[test]
+fn ownership_transfer_moves_whitelist_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+		assert_ok!(Sponsorship::add_to_whitelist(RuntimeOrigin::signed(1), 0, accounts(vec![4, 5])));
+		assert_ok!(Sponsorship::propose_pool_owner(RuntimeOrigin::signed(1), 0, 2));
+		assert_ok!(Sponsorship::accept_pool_ownership(RuntimeOrigin::signed(2), 0));
+
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 5020);
+
+		// Removing entries now refunds the new owner
+		assert_ok!(Sponsorship::remove_from_whitelist(RuntimeOrigin::signed(2), 0, accounts(vec![4, 5])));
+		assert_eq!(Balances::reserved_balance(2), 5000);
+		assert_eq!(Balances::free_balance(1), 10000 - 5000 - 20);
+	});
+}
+
+// Allowlist tests
+fn leaf(who: u64, quota: Option<u32>) -> H256 {
+	BlakeTwo256::hash_of(&(who, quota))
+}
+
+fn parent(a: H256, b: H256) -> H256 {
+	if a <= b { BlakeTwo256::hash_of(&(a, b)) } else { BlakeTwo256::hash_of(&(b, a)) }
+}
+
+fn allowlist_proof(quota: Option<u32>, siblings: Vec<H256>) -> Option<SponsorshipCredential<Test>> {
+	Some(SponsorshipCredential {
+		allowlist: Some(AllowlistProof { quota, proof: BoundedVec::try_from(siblings).unwrap() }),
+		..Default::default()
+	})
+}
+
+#[test]
+fn allowlist_proof_and_quota_are_enforced() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+
+		// Account 2 may use the pool once, account 3 without limit
+		let (leaf2, leaf3, leaf4) = (leaf(2, Some(1)), leaf(3, None), leaf(4, Some(5)));
+		let left = parent(leaf2, leaf3);
+		let root = parent(left, leaf4);
+
+		assert_noop!(
+			Sponsorship::set_allowlist_root(RuntimeOrigin::signed(2), 0, Some(root)),
+			Error::<Test>::NotPoolOwner
+		);
+		assert_ok!(Sponsorship::set_allowlist_root(RuntimeOrigin::signed(1), 0, Some(root)));
+		System::assert_last_event(Event::AllowlistRootSet { pool_id: 0, root: Some(root) }.into());
+
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None),
+			Error::<Test>::InvalidAllowlistProof
+		);
+		// Claiming a larger quota changes the leaf and breaks the proof
+		assert_noop!(
+			Sponsorship::sponsor_transaction(
+				RuntimeOrigin::signed(2),
+				0,
+				2006,
+				vec![1, 2, 3, 4],
+				allowlist_proof(Some(10), vec![leaf3, leaf4])
+			),
+			Error::<Test>::InvalidAllowlistProof
+		);
+
+		assert_ok!(Sponsorship::sponsor_transaction(
+			RuntimeOrigin::signed(2),
+			0,
+			2006,
+			vec![1, 2, 3, 4],
+			allowlist_proof(Some(1), vec![leaf3, leaf4])
+		));
+		assert_eq!(Sponsorship::allowlist_usage(0, 2), 1);
+		assert_noop!(
+			Sponsorship::sponsor_transaction(
+				RuntimeOrigin::signed(2),
+				0,
+				2006,
+				vec![1, 2, 3, 4],
+				allowlist_proof(Some(1), vec![leaf3, leaf4])
+			),
+			Error::<Test>::AllowlistQuotaExceeded
+		);
+
+		assert_ok!(Sponsorship::sponsor_transaction(
+			RuntimeOrigin::signed(3),
+			0,
+			2006,
+			vec![1, 2, 3, 4],
+			allowlist_proof(None, vec![leaf2, leaf4])
+		));
+		assert_ok!(Sponsorship::sponsor_transaction(
+			RuntimeOrigin::signed(4),
+			0,
+			2006,
+			vec![1, 2, 3, 4],
+			allowlist_proof(Some(5), vec![left])
+		));
+	});
+}
+
+#[test]
+fn allowlist_root_rotation_keeps_usage() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+
+		let (leaf2, leaf3) = (leaf(2, Some(1)), leaf(3, None));
+		assert_ok!(Sponsorship::set_allowlist_root(RuntimeOrigin::signed(1), 0, Some(parent(leaf2, leaf3))));
+		assert_ok!(Sponsorship::sponsor_transaction(
+			RuntimeOrigin::signed(2),
+			0,
+			2006,
+			vec![1, 2, 3, 4],
+			allowlist_proof(Some(1), vec![leaf3])
+		));
+
+		// The new root drops account 3 and raises account 2's quota to 2
+		let leaf2 = leaf(2, Some(2));
+		let leaf5 = leaf(5, None);
+		assert_ok!(Sponsorship::set_allowlist_root(RuntimeOrigin::signed(1), 0, Some(parent(leaf2, leaf5))));
+
+		assert_noop!(
+			Sponsorship::sponsor_transaction(
+				RuntimeOrigin::signed(3),
+				0,
+				2006,
+				vec![1, 2, 3, 4],
+				allowlist_proof(None, vec![leaf(2, Some(1))])
+			),
+			Error::<Test>::InvalidAllowlistProof
+		);
+		assert_ok!(Sponsorship::sponsor_transaction(
+			RuntimeOrigin::signed(2),
+			0,
+			2006,
+			vec![1, 2, 3, 4],
+			allowlist_proof(Some(2), vec![leaf5])
+		));
+		assert_noop!(
+			Sponsorship::sponsor_transaction(
+				RuntimeOrigin::signed(2),
+				0,
+				2006,
+				vec![1, 2, 3, 4],
+				allowlist_proof(Some(2), vec![leaf5])
+			),
+			Error::<Test>::AllowlistQuotaExceeded
+		);
+
+		// Removing the root opens the pool again
+		assert_ok!(Sponsorship::set_allowlist_root(RuntimeOrigin::signed(1), 0, None));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2006, vec![1, 2, 3, 4], None));
+	});
+}
+
+#[test]
+fn allowlist_root_follows_pool_roles() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+		assert_ok!(Sponsorship::grant_pool_role(RuntimeOrigin::signed(1), 0, 2, PoolRole::Admin));
+		assert_ok!(Sponsorship::grant_pool_role(RuntimeOrigin::signed(1), 0, 3, PoolRole::Operator));
+
+		let root = leaf(2, None);
+		assert_ok!(Sponsorship::set_allowlist_root(RuntimeOrigin::signed(2), 0, Some(root)));
+		System::assert_last_event(Event::AllowlistRootSet { pool_id: 0, root: Some(root) }.into());
+		assert_eq!(Sponsorship::pool_allowlist_root(0), Some(root));
+
+		assert_noop!(
+			Sponsorship::set_allowlist_root(RuntimeOrigin::signed(3), 0, None),
+			Error::<Test>::InsufficientPoolRole
+		);
+		assert_ok!(Sponsorship::grant_pool_role(RuntimeOrigin::signed(1), 0, 3, PoolRole::Auditor));
+		assert_noop!(
+			Sponsorship::set_allowlist_root(RuntimeOrigin::signed(3), 0, None),
+			Error::<Test>::InsufficientPoolRole
+		);
+		assert_noop!(
+			Sponsorship::set_allowlist_root(RuntimeOrigin::signed(4), 0, None),
+			Error::<Test>::NotPoolOwner
+		);
+	});
+}
+
+// Deny list tests
+#[test]
+fn pool_deny_list_overrides_allow_rules() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+		assert_ok!(Sponsorship::add_authorization_rule(
+			RuntimeOrigin::signed(1),
+			0,
+			rule(vec![RuleNode::AllowAccounts(BoundedVec::try_from(vec![2, 3]).unwrap())])
+		));
+
+		assert_noop!(
+			Sponsorship::add_to_pool_deny_list(RuntimeOrigin::signed(3), 0, 2),
+			Error::<Test>::NotPoolOwner
+		);
+		assert_ok!(Sponsorship::add_to_pool_deny_list(RuntimeOrigin::signed(1), 0, 2));
+		System::assert_last_event(Event::PoolDenyListAdded { pool_id: 0, who: 2 }.into());
+		assert_noop!(
+			Sponsorship::add_to_pool_deny_list(RuntimeOrigin::signed(1), 0, 2),
+			Error::<Test>::AlreadyDenied
+		);
+
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None),
+			Error::<Test>::AccountDenied
+		);
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2006, vec![1, 2, 3, 4], None));
+
+		assert_ok!(Sponsorship::remove_from_pool_deny_list(RuntimeOrigin::signed(1), 0, 2));
+		System::assert_last_event(Event::PoolDenyListRemoved { pool_id: 0, who: 2 }.into());
+		assert_noop!(
+			Sponsorship::remove_from_pool_deny_list(RuntimeOrigin::signed(1), 0, 2),
+			Error::<Test>::NotDenied
+		);
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+	});
+}
+
+#[test]
+fn global_deny_list_blocks_every_pool() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config.clone()));
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(2), 5000, config));
+
+		assert_noop!(
+			Sponsorship::add_to_global_deny_list(RuntimeOrigin::signed(1), 3),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Sponsorship::add_to_global_deny_list(RuntimeOrigin::root(), 3));
+		System::assert_last_event(Event::GlobalDenyListAdded { who: 3 }.into());
+
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 1, 2006, vec![1, 2, 3, 4], None),
+			Error::<Test>::AccountDeniedGlobally
+		);
+		assert_noop!(
+			Sponsorship::sponsor_transaction_auto(
+				RuntimeOrigin::signed(3),
+				2006,
+				vec![1, 2, 3, 4],
+				candidates(2),
+				PoolSelectionPreference::Any
+			),
+			Error::<Test>::AccountDeniedGlobally
+		);
+
+		// A pool-level denial only moves automatic selection on to the next pool
+		assert_ok!(Sponsorship::add_to_pool_deny_list(RuntimeOrigin::signed(1), 0, 2));
+		assert_ok!(Sponsorship::sponsor_transaction_auto(
+			RuntimeOrigin::signed(2),
+			2006,
+			vec![1, 2, 3, 4],
+			candidates(2),
+			PoolSelectionPreference::Any
+		));
+		assert_eq!(Sponsorship::pools(0).unwrap().balance, 5000);
+		assert_eq!(Sponsorship::pools(1).unwrap().balance, 5000 - 808);
+
+		assert_ok!(Sponsorship::remove_from_global_deny_list(RuntimeOrigin::root(), 3));
+		System::assert_last_event(Event::GlobalDenyListRemoved { who: 3 }.into());
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 1, 2006, vec![1, 2, 3, 4], None));
+	});
+}
+
+// Voucher tests
+fn voucher(user: u64, nonce: u64, call: VoucherCall<Test>) -> SponsorshipVoucher<Test> {
+	SponsorshipVoucher { pool_id: 0, user, target_chain: 2006, call, max_fee: 1000, expires_at: 10, nonce }
+}
+
+fn signed(signer: u64, voucher: SponsorshipVoucher<Test>) -> Option<SponsorshipCredential<Test>> {
+	let signature = TestSignature(signer, Sponsorship::voucher_signing_payload(&voucher));
+	Some(SponsorshipCredential { voucher: Some(SignedVoucher { voucher, signature }), ..Default::default() })
+}
+
+#[test]
+fn voucher_replaces_pool_rules_once_per_nonce() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		// No rules and an allowlist nobody can prove: only vouchers get through
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+		assert_ok!(Sponsorship::set_allowlist_root(RuntimeOrigin::signed(1), 0, Some(H256::zero())));
+
+		let call_data = vec![1, 2, 3, 4];
+		let by_hash = VoucherCall::CallHash(BlakeTwo256::hash(&call_data));
+		assert_ok!(Sponsorship::sponsor_transaction(
+			RuntimeOrigin::signed(2),
+			0,
+			2006,
+			call_data.clone(),
+			signed(1, voucher(2, 0, by_hash.clone()))
+		));
+		System::assert_has_event(Event::VoucherRedeemed { pool_id: 0, nonce: 0, transaction_id: 0 }.into());
+		assert_eq!(Sponsorship::allowlist_usage(0, 2), 0);
+
+		assert_noop!(
+			Sponsorship::sponsor_transaction(
+				RuntimeOrigin::signed(2),
+				0,
+				2006,
+				call_data.clone(),
+				signed(1, voucher(2, 0, by_hash.clone()))
+			),
+			Error::<Test>::VoucherNonceUsed
+		);
+
+		// Deny lists still win over a voucher
+		assert_ok!(Sponsorship::add_to_pool_deny_list(RuntimeOrigin::signed(1), 0, 2));
+		assert_noop!(
+			Sponsorship::sponsor_transaction(
+				RuntimeOrigin::signed(2),
+				0,
+				2006,
+				call_data.clone(),
+				signed(1, voucher(2, 1, by_hash))
+			),
+			Error::<Test>::AccountDenied
+		);
+	});
+}
+
+#[test]
+fn voucher_must_match_transaction_and_signer() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+
+		let sponsor = |who: u64, credential| {
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(who), 0, 2006, vec![1, 2, 3, 4], credential)
+		};
+		let by_index = VoucherCall::CallIndex(1, 2);
+
+		// Unknown signer, another user, another call
+		assert_noop!(sponsor(2, signed(9, voucher(2, 0, by_index.clone()))), Error::<Test>::InvalidVoucher);
+		assert_noop!(sponsor(3, signed(1, voucher(2, 0, by_index.clone()))), Error::<Test>::InvalidVoucher);
+		assert_noop!(
+			sponsor(2, signed(1, voucher(2, 0, VoucherCall::CallIndex(1, 3)))),
+			Error::<Test>::InvalidVoucher
+		);
+		assert_noop!(
+			sponsor(2, signed(1, SponsorshipVoucher { max_fee: 807, ..voucher(2, 0, by_index.clone()) })),
+			Error::<Test>::VoucherFeeExceeded
+		);
+
+		// A delegated key signs on the owner's behalf
+		assert_noop!(
+			Sponsorship::set_voucher_signer(RuntimeOrigin::signed(2), 0, Some(9)),
+			Error::<Test>::NotPoolOwner
+		);
+		assert_ok!(Sponsorship::set_voucher_signer(RuntimeOrigin::signed(1), 0, Some(9)));
+		System::assert_last_event(Event::VoucherSignerSet { pool_id: 0, signer: Some(9) }.into());
+		assert_ok!(sponsor(2, signed(9, voucher(2, 0, by_index.clone()))));
+		assert_ok!(sponsor(2, signed(1, voucher(2, 1, by_index.clone()))));
+
+		System::set_block_number(11);
+		assert_noop!(sponsor(2, signed(9, voucher(2, 2, by_index.clone()))), Error::<Test>::VoucherExpired);
+
+		assert_ok!(Sponsorship::set_voucher_signer(RuntimeOrigin::signed(1), 0, None));
+		assert_noop!(
+			sponsor(2, signed(9, SponsorshipVoucher { expires_at: 20, ..voucher(2, 2, by_index) })),
+			Error::<Test>::InvalidVoucher
+		);
+	});
+}
+
+#[test]
+fn voucher_signer_follows_pool_roles() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+		assert_ok!(Sponsorship::grant_pool_role(RuntimeOrigin::signed(1), 0, 2, PoolRole::Admin));
+		assert_ok!(Sponsorship::grant_pool_role(RuntimeOrigin::signed(1), 0, 3, PoolRole::Operator));
+
+		// An admin delegates signing, and vouchers from the delegated key are honoured
+		assert_ok!(Sponsorship::set_voucher_signer(RuntimeOrigin::signed(2), 0, Some(9)));
+		System::assert_last_event(Event::VoucherSignerSet { pool_id: 0, signer: Some(9) }.into());
+		assert_eq!(Sponsorship::voucher_signer(0), Some(9));
+		assert_ok!(Sponsorship::sponsor_transaction(
+			RuntimeOrigin::signed(3),
+			0,
+			2006,
+			vec![1, 2, 3, 4],
+			signed(9, voucher(3, 0, VoucherCall::CallIndex(1, 2)))
+		));
+
+		assert_noop!(
+			Sponsorship::set_voucher_signer(RuntimeOrigin::signed(3), 0, None),
+			Error::<Test>::InsufficientPoolRole
+		);
+		assert_ok!(Sponsorship::grant_pool_role(RuntimeOrigin::signed(1), 0, 3, PoolRole::Auditor));
+		assert_noop!(
+			Sponsorship::set_voucher_signer(RuntimeOrigin::signed(3), 0, None),
+			Error::<Test>::InsufficientPoolRole
+		);
+		assert_noop!(
+			Sponsorship::set_voucher_signer(RuntimeOrigin::signed(4), 0, None),
+			Error::<Test>::NotPoolOwner
+		);
+	});
+}
+
+#[test]
+fn voucher_signature_is_bound_to_chain_and_nonces_pruned_after_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+
+		let sponsor = |credential| {
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], credential)
+		};
+		let by_index = VoucherCall::CallIndex(1, 2);
+
+		// A signature over the bare voucher, or over another chain's genesis, is rejected
+		let untagged = voucher(2, 0, by_index.clone());
+		let signature = TestSignature(1, untagged.encode());
+		let credential = SponsorshipCredential {
+			voucher: Some(SignedVoucher { voucher: untagged.clone(), signature }),
+			..Default::default()
+		};
+		assert_noop!(sponsor(Some(credential)), Error::<Test>::InvalidVoucher);
+		let other_chain = (crate::VOUCHER_SIGNING_CONTEXT, H256::repeat_byte(1), &untagged).encode();
+		let credential = SponsorshipCredential {
+			voucher: Some(SignedVoucher { voucher: untagged, signature: TestSignature(1, other_chain) }),
+			..Default::default()
+		};
+		assert_noop!(sponsor(Some(credential)), Error::<Test>::InvalidVoucher);
+
+		// Redeemed nonces stay until the block after their voucher expires
+		assert_ok!(sponsor(signed(1, voucher(2, 0, by_index.clone()))));
+		assert!(Sponsorship::used_voucher_nonce(0, 0).is_some());
+		assert_eq!(Sponsorship::voucher_nonce_expiries(11).into_inner(), vec![(0, 0)]);
+
+		Sponsorship::on_initialize(10);
+		assert!(Sponsorship::used_voucher_nonce(0, 0).is_some());
+		Sponsorship::on_initialize(11);
+		assert!(Sponsorship::used_voucher_nonce(0, 0).is_none());
+		assert!(Sponsorship::voucher_nonce_expiries(11).is_empty());
+
+		// By then the voucher itself is expired, so the pruned nonce cannot be replayed
+		System::set_block_number(11);
+		assert_noop!(sponsor(signed(1, voucher(2, 0, by_index))), Error::<Test>::VoucherExpired);
+	});
+}
+
+#[test]
+fn voucher_redemptions_are_bounded_per_expiry_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		for nonce in 0..16 {
+			assert_ok!(Sponsorship::record_voucher_nonce(0, nonce, 10));
+		}
+		assert_noop!(Sponsorship::record_voucher_nonce(0, 16, 10), Error::<Test>::TooManyVoucherExpiries);
+		assert!(Sponsorship::used_voucher_nonce(0, 16).is_none());
+	});
+}
+
+// Attestation tests
+fn attested(attester: u64, subject: u64, score: u32, id: u64) -> Option<SponsorshipCredential<Test>> {
+	let attestation = Attestation { attester, subject, score, valid_until: 10, id };
+	let signature = TestSignature(attester, Sponsorship::attestation_signing_payload(&attestation));
+	Some(SponsorshipCredential {
+		attestation: Some(SignedAttestation { attestation, signature }),
+		..Default::default()
+	})
+}
+
+#[test]
+fn pool_attesters_can_be_added_removed_and_rotated() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+
+		assert_noop!(
+			Sponsorship::add_pool_attester(RuntimeOrigin::signed(2), 0, 20),
+			Error::<Test>::NotPoolOwner
+		);
+		for attester in 20..24 {
+			assert_ok!(Sponsorship::add_pool_attester(RuntimeOrigin::signed(1), 0, attester));
+		}
+		System::assert_last_event(Event::PoolAttesterAdded { pool_id: 0, attester: 23 }.into());
+		assert_noop!(
+			Sponsorship::add_pool_attester(RuntimeOrigin::signed(1), 0, 24),
+			Error::<Test>::TooManyAttesters
+		);
+
+		assert_ok!(Sponsorship::remove_pool_attester(RuntimeOrigin::signed(1), 0, 23));
+		assert_eq!(Sponsorship::pool_attester_count(0), 3);
+		assert_noop!(
+			Sponsorship::remove_pool_attester(RuntimeOrigin::signed(1), 0, 23),
+			Error::<Test>::AttesterNotTrusted
+		);
+		assert_noop!(
+			Sponsorship::add_pool_attester(RuntimeOrigin::signed(1), 0, 20),
+			Error::<Test>::AttesterAlreadyTrusted
+		);
+
+		assert_noop!(
+			Sponsorship::rotate_pool_attester(RuntimeOrigin::signed(1), 0, 20, 21),
+			Error::<Test>::AttesterAlreadyTrusted
+		);
+		assert_ok!(Sponsorship::rotate_pool_attester(RuntimeOrigin::signed(1), 0, 20, 25));
+		assert!(Sponsorship::pool_attester(0, 20).is_none());
+		assert!(Sponsorship::pool_attester(0, 25).is_some());
+		assert_eq!(Sponsorship::pool_attester_count(0), 3);
+	});
+}
+
+#[test]
+fn min_attested_score_rule_checks_attestation() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+		assert_ok!(Sponsorship::add_authorization_rule(
+			RuntimeOrigin::signed(1),
+			0,
+			rule(vec![RuleNode::MinAttestedScore(50)])
+		));
+		assert_ok!(Sponsorship::add_pool_attester(RuntimeOrigin::signed(1), 0, 20));
+
+		let sponsor = |who: u64, credential| {
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(who), 0, 2006, vec![1, 2, 3, 4], credential)
+		};
+
+		assert_noop!(sponsor(2, None), Error::<Test>::UnauthorizedUser);
+		assert_noop!(sponsor(2, attested(20, 2, 40, 0)), Error::<Test>::UnauthorizedUser);
+		assert_noop!(sponsor(2, attested(21, 2, 60, 0)), Error::<Test>::InvalidAttestation);
+		assert_noop!(sponsor(3, attested(20, 2, 60, 0)), Error::<Test>::InvalidAttestation);
+
+		// Signed by someone other than the named attester
+		let attestation = Attestation { attester: 20, subject: 2, score: 60, valid_until: 10, id: 0 };
+		let payload = Sponsorship::attestation_signing_payload(&attestation);
+		let forged = SignedAttestation { signature: TestSignature(21, payload), attestation: attestation.clone() };
+		assert_noop!(
+			sponsor(2, Some(SponsorshipCredential { attestation: Some(forged), ..Default::default() })),
+			Error::<Test>::InvalidAttestation
+		);
+
+		// The attester's key over the bare attestation, or over a voucher's domain tag
+		for message in [
+			attestation.encode(),
+			(crate::VOUCHER_SIGNING_CONTEXT, System::block_hash(0), &attestation).encode(),
+		] {
+			let untagged = SignedAttestation { signature: TestSignature(20, message), attestation: attestation.clone() };
+			assert_noop!(
+				sponsor(2, Some(SponsorshipCredential { attestation: Some(untagged), ..Default::default() })),
+				Error::<Test>::InvalidAttestation
+			);
+		}
+
+		assert_ok!(sponsor(2, attested(20, 2, 60, 0)));
+
+		assert_ok!(Sponsorship::revoke_attestation(RuntimeOrigin::signed(20), 0, 0, 10));
+		System::assert_last_event(Event::AttestationRevoked { attester: 20, id: 0 }.into());
+		assert_noop!(sponsor(2, attested(20, 2, 60, 0)), Error::<Test>::AttestationRevoked);
+		assert_ok!(sponsor(2, attested(20, 2, 60, 1)));
+
+		// Rotating the key retires the old attester's attestations
+		assert_ok!(Sponsorship::rotate_pool_attester(RuntimeOrigin::signed(1), 0, 20, 21));
+		assert_noop!(sponsor(2, attested(20, 2, 60, 1)), Error::<Test>::InvalidAttestation);
+		assert_ok!(sponsor(2, attested(21, 2, 60, 0)));
+
+		System::set_block_number(11);
+		assert_noop!(sponsor(2, attested(21, 2, 60, 2)), Error::<Test>::AttestationExpired);
+	});
+}
+
+#[test]
+fn revocations_need_a_trusted_attester_and_are_pruned_after_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+		assert_ok!(Sponsorship::add_pool_attester(RuntimeOrigin::signed(1), 0, 20));
+
+		// Only keys the named pool trusts can write revocations
+		assert_noop!(
+			Sponsorship::revoke_attestation(RuntimeOrigin::signed(21), 0, 0, 10),
+			Error::<Test>::AttesterNotTrusted
+		);
+		assert_noop!(
+			Sponsorship::revoke_attestation(RuntimeOrigin::signed(20), 1, 0, 10),
+			Error::<Test>::AttesterNotTrusted
+		);
+
+		assert_ok!(Sponsorship::revoke_attestation(RuntimeOrigin::signed(20), 0, 0, 10));
+		assert!(Sponsorship::revoked_attestation(20, 0).is_some());
+		assert_eq!(Sponsorship::revocation_expiries(11).into_inner(), vec![(20, 0)]);
+		assert_noop!(
+			Sponsorship::revoke_attestation(RuntimeOrigin::signed(20), 0, 0, 10),
+			Error::<Test>::AttestationRevoked
+		);
+
+		// Revocations are bounded per expiry block
+		for id in 1..16 {
+			assert_ok!(Sponsorship::revoke_attestation(RuntimeOrigin::signed(20), 0, id, 10));
+		}
+		assert_noop!(
+			Sponsorship::revoke_attestation(RuntimeOrigin::signed(20), 0, 16, 10),
+			Error::<Test>::TooManyRevocationExpiries
+		);
+
+		// Once the attestation has expired its revocation is no longer needed
+		System::set_block_number(11);
+		Sponsorship::on_initialize(11);
+		assert!(Sponsorship::revoked_attestation(20, 0).is_none());
+		assert!(Sponsorship::revocation_expiries(11).is_empty());
+		assert_noop!(
+			Sponsorship::revoke_attestation(RuntimeOrigin::signed(20), 0, 16, 10),
+			Error::<Test>::AttestationExpired
+		);
+	});
+}
+
+#[test]
+fn allowlisted_pool_checks_attestation_alongside_proof() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+		let (leaf2, leaf3) = (leaf(2, None), leaf(3, None));
+		assert_ok!(Sponsorship::set_allowlist_root(RuntimeOrigin::signed(1), 0, Some(parent(leaf2, leaf3))));
+		assert_ok!(Sponsorship::add_authorization_rule(
+			RuntimeOrigin::signed(1),
+			0,
+			rule(vec![RuleNode::MinAttestedScore(50)])
+		));
+		assert_ok!(Sponsorship::add_pool_attester(RuntimeOrigin::signed(1), 0, 20));
+
+		let sponsor = |credential| {
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], credential)
+		};
+		let both = |score: u32, id: u64| {
+			Some(SponsorshipCredential {
+				attestation: attested(20, 2, score, id).unwrap().attestation,
+				..allowlist_proof(None, vec![leaf3]).unwrap()
+			})
+		};
+
+		// Either part alone is not enough
+		assert_noop!(sponsor(allowlist_proof(None, vec![leaf3])), Error::<Test>::UnauthorizedUser);
+		assert_noop!(sponsor(attested(20, 2, 60, 0)), Error::<Test>::InvalidAllowlistProof);
+		assert_noop!(sponsor(both(40, 0)), Error::<Test>::UnauthorizedUser);
+
+		assert_ok!(sponsor(both(60, 0)));
+	});
+}
+
+// Holding and account age rule tests
+#[test]
+fn min_asset_balance_rule_checks_asset_holdings() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+		assert_ok!(Sponsorship::add_authorization_rule(
+			RuntimeOrigin::signed(1),
+			0,
+			rule(vec![RuleNode::MinAssetBalance { asset: 7, amount: 50 }])
+		));
+
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 7, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 7, 2, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 7, 3, 49));
+
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2006, vec![1, 2, 3, 4], None),
+			Error::<Test>::UnauthorizedUser
+		);
+
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 7, 3, 1));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2006, vec![1, 2, 3, 4], None));
+	});
+}
+
+#[test]
+fn account_age_and_nonce_rules_exclude_new_accounts() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(30);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+		assert_ok!(Sponsorship::add_authorization_rule(
+			RuntimeOrigin::signed(1),
+			0,
+			rule(vec![RuleNode::MinAccountAge(20)])
+		));
+
+		let sponsor =
+			|who: u64| Sponsorship::sponsor_transaction(RuntimeOrigin::signed(who), 0, 2006, vec![1, 2, 3, 4], None);
+
+		// Genesis accounts are old enough, a fresh or missing account is not
+		assert_eq!(Sponsorship::account_first_seen(2), Some(0));
+		assert_ok!(sponsor(2));
+		assert_noop!(sponsor(5), Error::<Test>::UnauthorizedUser);
+
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 5, 1000));
+		assert_eq!(Sponsorship::account_first_seen(5), Some(30));
+		assert_noop!(sponsor(5), Error::<Test>::UnauthorizedUser);
+
+		System::set_block_number(50);
+		assert_ok!(sponsor(5));
+
+		// Reaping the account forgets when it was created
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 5, 0));
+		assert_eq!(Sponsorship::account_first_seen(5), None);
+		assert_noop!(sponsor(5), Error::<Test>::UnauthorizedUser);
+
+		// Accounts created before tracking started count as old
+		crate::AccountFirstSeen::<Test>::remove(3);
+		assert_ok!(sponsor(3));
+
+		// A nonce rule must hold as well
+		assert_ok!(Sponsorship::add_authorization_rule(
+			RuntimeOrigin::signed(1),
+			0,
+			rule(vec![RuleNode::MinAccountNonce(1)])
+		));
+		assert_noop!(sponsor(2), Error::<Test>::UnauthorizedUser);
+		System::inc_account_nonce(2);
+		assert_ok!(sponsor(2));
+	});
+}
+
+#[test]
+fn recreating_a_dusted_account_restarts_its_age() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+		assert_ok!(Sponsorship::add_authorization_rule(
+			RuntimeOrigin::signed(1),
+			0,
+			rule(vec![RuleNode::MinAccountAge(20)])
+		));
+
+		let sponsor =
+			|who: u64| Sponsorship::sponsor_transaction(RuntimeOrigin::signed(who), 0, 2006, vec![1, 2, 3, 4], None);
+
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 5, 1000));
+		System::set_block_number(30);
+		assert_ok!(sponsor(5));
+
+		// Dropping below the existential deposit reaps the account and its creation block
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(5), 3, 600));
+		assert!(!System::account_exists(&5));
+		assert_eq!(Sponsorship::account_first_seen(5), None);
+
+		// Funding it again counts as a new account rather than an old untracked one
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), 5, 1000));
+		assert_eq!(Sponsorship::account_first_seen(5), Some(30));
+		assert_noop!(sponsor(5), Error::<Test>::UnauthorizedUser);
+
+		System::set_block_number(50);
+		assert_ok!(sponsor(5));
+	});
+}
+
+// NFT holder rule tests
+#[test]
+fn nft_holder_rule_limits_use_per_item() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+
+		// Only one condition of a rule may carry a per-item limit
+		assert_noop!(
+			Sponsorship::add_authorization_rule(
+				RuntimeOrigin::signed(1),
+				0,
+				rule(vec![
+					RuleNode::Any(2),
+					RuleNode::NftHolder { collection: 1, max_per_item_per_day: Some(2) },
+					RuleNode::NftHolder { collection: 2, max_per_item_per_day: Some(2) },
+				])
+			),
+			Error::<Test>::InvalidRule
+		);
+		assert_ok!(Sponsorship::add_authorization_rule(
+			RuntimeOrigin::signed(1),
+			0,
+			rule(vec![RuleNode::NftHolder { collection: 1, max_per_item_per_day: Some(2) }])
+		));
+
+		NftHolders::set(vec![(1, 10, 2), (2, 10, 3)]);
+		let sponsor =
+			|who: u64| Sponsorship::sponsor_transaction(RuntimeOrigin::signed(who), 0, 2006, vec![1, 2, 3, 4], None);
+
+		assert_noop!(sponsor(3), Error::<Test>::UnauthorizedUser);
+		assert_ok!(sponsor(2));
+		assert_ok!(sponsor(2));
+		assert_eq!(Sponsorship::nft_item_usage(0, (1, 10)), (0, 2));
+		assert_noop!(sponsor(2), Error::<Test>::UnauthorizedUser);
+
+		// Passing the item on does not reset its limit
+		NftHolders::set(vec![(1, 10, 3)]);
+		assert_noop!(sponsor(3), Error::<Test>::UnauthorizedUser);
+
+		System::set_block_number(101);
+		assert_ok!(sponsor(3));
+		assert_eq!(Sponsorship::nft_item_usage(0, (1, 10)), (1, 1));
+	});
+}
+
+#[test]
+fn nft_holder_rule_uses_items_with_quota_left_only_when_decisive() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 4000, config.clone()));
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 4000, config));
+		// Pool 0 sponsors account 2 outright and NFT holders once per item and day
+		assert_ok!(Sponsorship::add_authorization_rule(
+			RuntimeOrigin::signed(1),
+			0,
+			rule(vec![
+				RuleNode::Any(2),
+				RuleNode::AllowAccounts(BoundedVec::try_from(vec![2]).unwrap()),
+				RuleNode::NftHolder { collection: 1, max_per_item_per_day: Some(1) },
+			])
+		));
+		assert_ok!(Sponsorship::add_authorization_rule(
+			RuntimeOrigin::signed(1),
+			1,
+			rule(vec![RuleNode::NftHolder { collection: 1, max_per_item_per_day: Some(1) }])
+		));
+
+		// Only the first two of account 2's items are looked at
+		NftHolders::set(vec![(1, 10, 2), (1, 11, 2), (1, 13, 2), (1, 12, 3)]);
+		let sponsor = |who: u64, pool_id: u32| {
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(who), pool_id, 2006, vec![1, 2, 3, 4], None)
+		};
+
+		// The allowlist decides for account 2, so its items stay unused
+		assert_ok!(sponsor(2, 0));
+		assert_eq!(Sponsorship::nft_item_usage(0, (1, 10)), (0, 0));
+		assert_ok!(sponsor(3, 0));
+		assert_eq!(Sponsorship::nft_item_usage(0, (1, 12)), (0, 1));
+		assert_noop!(sponsor(3, 0), Error::<Test>::UnauthorizedUser);
+
+		// Once an item is used up the next one is picked
+		assert_ok!(sponsor(2, 1));
+		assert_ok!(sponsor(2, 1));
+		assert_eq!(Sponsorship::nft_item_usage(1, (1, 10)), (0, 1));
+		assert_eq!(Sponsorship::nft_item_usage(1, (1, 11)), (0, 1));
+		assert_noop!(sponsor(2, 1), Error::<Test>::UnauthorizedUser);
+	});
+}
+
+// Identity judgement rule tests
+#[test]
+fn identity_judgement_rule_requires_chosen_registrar() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: true,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 5000, config));
+		assert_ok!(Sponsorship::add_authorization_rule(
+			RuntimeOrigin::signed(1),
+			0,
+			rule(vec![RuleNode::IdentityJudgement(BoundedVec::try_from(vec![0, 2]).unwrap())])
+		));
+
+		// Account 3 is only vouched for by a registrar the pool does not trust
+		GoodJudgements::set(vec![(2, 2), (3, 1)]);
+		let sponsor =
+			|who: u64| Sponsorship::sponsor_transaction(RuntimeOrigin::signed(who), 0, 2006, vec![1, 2, 3, 4], None);
+
+		assert_ok!(sponsor(2));
+		assert_noop!(sponsor(3), Error::<Test>::UnauthorizedUser);
+		assert_noop!(sponsor(4), Error::<Test>::UnauthorizedUser);
+
+		GoodJudgements::set(vec![(2, 2), (3, 1), (3, 0)]);
+		assert_ok!(sponsor(3));
+	});
+}
+
+// Runtime API v2 helper tests
+#[test]
+fn transaction_history_pages_newest_first() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(3), 10000, config.clone()));
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(2), 5000, config));
+
+		// Transactions 0, 2, 4 go to pool 0; 1 and 3 to pool 1
+		for i in 0..5u32 {
+			assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(1), i % 2, 2006, vec![1, 2, 3, 4], None));
+		}
+
+		let (page, cursor) = Sponsorship::get_pool_transaction_page(0, None, 2);
+		assert_eq!(page.iter().map(|tx| tx.id).collect::<Vec<_>>(), vec![4, 2]);
+		assert_eq!(cursor, Some(2));
+
+		let (page, cursor) = Sponsorship::get_pool_transaction_page(0, cursor, 2);
+		assert_eq!(page.iter().map(|tx| tx.id).collect::<Vec<_>>(), vec![0]);
+		assert_eq!(cursor, None);
+	});
+}
+
+#[test]
+fn pool_stats_aggregate_transactions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006, 2030]).unwrap(), // Astar, Bifrost
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 10000, config));
+
+		// Day 0: three transactions, one settled by a first-time user
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(3), 0, 2030, vec![1, 2, 3, 4], None));
+		assert_ok!(Sponsorship::process_receipt(RuntimeOrigin::root(), receipt(1, 2006, ExecutionResult::Success, 600)));
+
+		// Day 1: a returning user and a new user
+		System::set_block_number(150);
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_ok!(Sponsorship::process_receipt(
+			RuntimeOrigin::root(),
+			receipt(2, 2030, ExecutionResult::Failed(BoundedVec::try_from(b"out of gas".to_vec()).unwrap()), 700)
+		));
+		assert_ok!(Sponsorship::process_receipt(RuntimeOrigin::root(), receipt(3, 2006, ExecutionResult::Success, 400)));
+
+		let stats = Sponsorship::get_pool_stats(0, None, None);
+		assert_eq!((stats.from_day, stats.to_day), (0, 1));
+		assert_eq!(stats.sponsored_count, 4);
+		assert_eq!(stats.executed_count, 2);
+		assert_eq!(stats.failed_count, 1);
+		assert_eq!(stats.refunded_count, 0);
+		assert_eq!(stats.pending_count, 1);
+		assert_eq!(stats.total_spent, 600 + 700 + 400);
+		assert_eq!(stats.average_fee, 566);
+		assert_eq!(stats.new_user_count, 2);
+		assert_eq!(stats.new_user_spent, 600 + 700);
+		assert_eq!(stats.returning_user_spent, 400);
+		assert_eq!(stats.by_chain.len(), 2);
+		assert_eq!(stats.by_chain[0].target_chain, 2006);
+		assert_eq!(stats.by_chain[0].settled_count, 2);
+		assert_eq!(stats.by_chain[0].total_spent, 1000);
+		assert_eq!(stats.by_chain[1].target_chain, 2030);
+		assert_eq!(stats.by_chain[1].total_spent, 700);
+		assert_eq!(stats.by_day.len(), 2);
+		assert_eq!(stats.by_day[0].1.sponsored_count, 3);
+		assert_eq!(stats.by_day[1].1.sponsored_count, 1);
+
+		// Restricting the range to day 0 leaves out later settlements
+		let stats = Sponsorship::get_pool_stats(0, Some(0), Some(99));
+		assert_eq!((stats.from_day, stats.to_day), (0, 0));
+		assert_eq!(stats.sponsored_count, 3);
+		assert_eq!(stats.executed_count, 1);
+		assert_eq!(stats.total_spent, 600);
+		assert_eq!(stats.by_day.len(), 1);
+	});
+}
+
+#[test]
+fn forecast_projects_runway_from_recent_spend() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 10000, config));
+
+		// Nothing settled yet, so there is no burn rate to project from
+		let forecast = Sponsorship::forecast_pool_runway(0, 30).unwrap();
+		assert_eq!(forecast.daily_burn_rate, 0);
+		assert_eq!(forecast.runway_days, None);
+		assert_eq!(forecast.depletion_block, None);
+		assert_eq!(forecast.recommended_top_up, 0);
+
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_ok!(Sponsorship::process_receipt(RuntimeOrigin::root(), receipt(1, 2006, ExecutionResult::Success, 600)));
+
+		System::set_block_number(250);
+		assert_ok!(Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None));
+		assert_ok!(Sponsorship::process_receipt(RuntimeOrigin::root(), receipt(2, 2006, ExecutionResult::Success, 600)));
+
+		// 1200 settled over days 0..=2
+		let forecast = Sponsorship::forecast_pool_runway(0, 30).unwrap();
+		assert_eq!(forecast.balance, 7784);
+		assert_eq!(forecast.sample_days, 3);
+		assert_eq!(forecast.daily_burn_rate, 400);
+		assert_eq!(forecast.runway_days, Some(19));
+		assert_eq!(forecast.depletion_block, Some(250 + 1946));
+		assert_eq!(forecast.recommended_top_up, 30 * 400 - 7784);
+
+		// A week later the old spend has left the sampling window
+		System::set_block_number(1000);
+		let forecast = Sponsorship::forecast_pool_runway(0, 30).unwrap();
+		assert_eq!(forecast.sample_days, 7);
+		assert_eq!(forecast.daily_burn_rate, 0);
+		assert_eq!(forecast.runway_days, None);
+
+		assert_eq!(Sponsorship::forecast_pool_runway(1, 30), None);
+	});
+}
+
+#[test]
+fn dry_run_reports_outcome_without_changing_state() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config));
+
+		let result = Sponsorship::dry_run_sponsorship(2, 0, 2006, vec![1, 2, 3, 4], None);
+		assert_eq!(result.estimated_fee, 808);
+		assert_eq!(result.pool_balance_after, Some(1192));
+		assert_eq!(result.error, None);
+
+		// Nothing was committed
+		assert_eq!(Sponsorship::pools(0).unwrap().balance, 2000);
+		assert_eq!(Sponsorship::next_transaction_id(), 0);
+
+		let result = Sponsorship::dry_run_sponsorship(2, 0, 2000, vec![1, 2, 3, 4], None);
+		assert_eq!(result.error, Some(b"ChainNotSupported".to_vec()));
+	});
+}
+
+#[test]
+fn dry_run_checks_the_supplied_credential() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let config = PoolConfig {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(), // Astar
+			authorization_required: false,
+			campaign_tag: None,
+			low_balance_threshold: 0,
+			expires_at: None,
+			schedule: None,
+			config_update_delay: 0,
+		};
+
+		assert_ok!(Sponsorship::create_pool(RuntimeOrigin::signed(1), 2000, config));
+		let (leaf2, leaf3) = (leaf(2, Some(1)), leaf(3, None));
+		assert_ok!(Sponsorship::set_allowlist_root(RuntimeOrigin::signed(1), 0, Some(parent(leaf2, leaf3))));
+
+		let result = Sponsorship::dry_run_sponsorship(2, 0, 2006, vec![1, 2, 3, 4], None);
+		assert_eq!(result.error, Some(b"InvalidAllowlistProof".to_vec()));
+
+		let proof = allowlist_proof(Some(1), vec![leaf3]).map(|credential| credential.encode());
+		let result = Sponsorship::dry_run_sponsorship(2, 0, 2006, vec![1, 2, 3, 4], proof);
+		assert_eq!(result.error, None);
+		assert_eq!(result.pool_balance_after, Some(1192));
+		assert_eq!(Sponsorship::allowlist_usage(0, 2), 0);
+
+		let result = Sponsorship::dry_run_sponsorship(2, 0, 2006, vec![1, 2, 3, 4], Some(vec![0xff]));
+		assert_eq!(result.error, Some(b"InvalidCredential".to_vec()));
+	});
+}
+
+// Migration tests
+#[test]
+fn migrate_to_v1_translates_pools_and_rebuilds_derived_storage() {
+	use crate::migrations::{v0, v1};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Sponsorship>();
+
+		let old_config = |authorization_required| v0::PoolConfig::<Test> {
+			max_transaction_value: 1000,
+			daily_spending_limit: 5000,
+			allowed_chains: BoundedVec::try_from(vec![2006]).unwrap(),
+			authorization_required,
+		};
+		let old_pool = |owner: u64, status| v0::PoolInfo::<Test> {
+			owner,
+			balance: 4000,
+			total_spent: 1000,
+			created_at: 1,
+			config: old_config(owner == 1),
+			status,
+		};
+		v0::Pools::<Test>::insert(0, old_pool(1, PoolStatus::Active));
+		v0::Pools::<Test>::insert(1, old_pool(1, PoolStatus::Paused));
+		v0::Pools::<Test>::insert(2, old_pool(2, PoolStatus::Closed));
+		v0::PoolAuthorizations::<Test>::insert(
+			0,
+			v0::AuthorizationType::UserWhitelist,
+			v0::AuthorizationRule { rule_type: v0::AuthorizationType::UserWhitelist, data: Default::default(), created_at: 1 },
+		);
+		// Only the most recent ids are checked for transactions in flight
+		let recent = v1::IN_FLIGHT_SCAN_WINDOW;
+		for (id, status) in [
+			(0, TransactionStatus::Dispatched),
+			(recent, TransactionStatus::Dispatched),
+			(recent + 1, TransactionStatus::Executed),
+		] {
+			crate::TransactionLog::<Test>::insert(
+				id,
+				crate::TransactionRecord {
+					id,
+					pool_id: 0,
+					user: 2,
+					target_chain: 2006,
+					call_hash: H256::zero(),
+					gas_cost: 808,
+					status,
+					timestamp: 1,
+				},
+			);
+		}
+		crate::NextTransactionId::<Test>::put(recent + 2);
+
+		v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(Sponsorship::on_chain_storage_version(), 1);
+
+		let pool = Sponsorship::pools(0).unwrap();
+		assert_eq!(pool.balance, 4000);
+		assert!(pool.config.authorization_required);
+		assert_eq!(pool.config.expires_at, None);
+		assert_eq!(pool.config.config_update_delay, 0);
+		assert_eq!(Sponsorship::pools(2).unwrap().status, PoolStatus::Closed);
+
+		// Old rules are gone, so the pool requiring authorization sponsors nobody
+		assert_eq!(crate::PoolAuthorizations::<Test>::iter_keys().count(), 0);
+		assert_noop!(
+			Sponsorship::sponsor_transaction(RuntimeOrigin::signed(2), 0, 2006, vec![1, 2, 3, 4], None),
+			Error::<Test>::UnauthorizedUser
+		);
+
+		// Closed pools count neither towards the owner's limit nor as contributions
+		assert_eq!(Sponsorship::owned_pool_count(1), 2);
+		assert_eq!(Sponsorship::owned_pool_count(2), 0);
+		assert_eq!(Sponsorship::pool_contributors(0, 1), 5000);
+		assert_eq!(Sponsorship::pool_contributions(0).contributors, 1);
+		assert_eq!(Sponsorship::pool_contributors(2, 2), 0);
+		assert_eq!(Sponsorship::pool_in_flight(0), 1);
+		assert!(Sponsorship::chain_pools(2006, 1).is_some());
+		assert!(Sponsorship::chain_pools(2006, 2).is_none());
+
+		// Running it again is a no-op
+		crate::Pools::<Test>::mutate(0, |pool| pool.as_mut().unwrap().balance = 1);
+		v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(Sponsorship::pools(0).unwrap().balance, 1);
+	});
+}
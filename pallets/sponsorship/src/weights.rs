@@ -1,24 +1,11 @@
-//! Autogenerated weights for pallet_sponsorship
+//! Weights for pallet_sponsorship
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-06-16, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `runner-e8ezs4ez-project-145-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// ./target/production/substrate
-// benchmark
-// pallet
-// --chain=dev
-// --steps=50
-// --repeat=20
-// --pallet=pallet_sponsorship
-// --extrinsic=*
-// --execution=wasm
-// --wasm-execution=compiled
-// --template=./.maintain/frame-weight-template.hbs
-// --output=./pallets/sponsorship/src/weights.rs
+//! These are hand-maintained estimates laid out like `frame-benchmarking` output, not
+//! measurements. Replace them with benchmark results before relying on them in production:
+//!
+//! ./target/release/gasleap-node benchmark pallet --chain=dev --steps=50 --repeat=20
+//!     --pallet=pallet_sponsorship --extrinsic=* --execution=wasm --wasm-execution=compiled
+//!     --template=./.maintain/frame-weight-template.hbs --output=./pallets/sponsorship/src/weights.rs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -36,6 +23,37 @@ pub trait WeightInfo {
 	fn remove_authorization_rule() -> Weight;
 	fn sponsor_transaction() -> Weight;
 	fn process_receipt() -> Weight;
+	fn sponsor_transaction_auto(c: u32, ) -> Weight;
+	fn set_auto_top_up() -> Weight;
+	fn clear_auto_top_up() -> Weight;
+	fn on_initialize_auto_top_up(n: u32, ) -> Weight;
+	fn close_pool(c: u32, ) -> Weight;
+	fn grant_pool_role() -> Weight;
+	fn revoke_pool_role() -> Weight;
+	fn pause_pool() -> Weight;
+	fn resume_pool() -> Weight;
+	fn propose_pool_owner() -> Weight;
+	fn accept_pool_ownership() -> Weight;
+	fn cancel_pool_config_update() -> Weight;
+	fn on_initialize_config_updates(n: u32, ) -> Weight;
+	fn on_initialize_voucher_nonces(n: u32, ) -> Weight;
+	fn on_initialize_revocations(n: u32, ) -> Weight;
+	fn evaluate_rules(r: u32, n: u32, ) -> Weight;
+	fn add_to_whitelist(n: u32, ) -> Weight;
+	fn remove_from_whitelist(n: u32, ) -> Weight;
+	fn set_allowlist_root() -> Weight;
+	fn verify_allowlist_proof(p: u32, ) -> Weight;
+	fn add_to_pool_deny_list() -> Weight;
+	fn remove_from_pool_deny_list() -> Weight;
+	fn add_to_global_deny_list() -> Weight;
+	fn remove_from_global_deny_list() -> Weight;
+	fn set_voucher_signer() -> Weight;
+	fn verify_voucher() -> Weight;
+	fn add_pool_attester() -> Weight;
+	fn remove_pool_attester() -> Weight;
+	fn rotate_pool_attester() -> Weight;
+	fn revoke_attestation() -> Weight;
+	fn verify_attestation() -> Weight;
 }
 
 /// Weights for pallet_sponsorship using the Substrate node and recommended hardware.
@@ -47,62 +65,96 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
 	/// Storage: Sponsorship PoolOwners (r:0 w:1)
 	/// Proof: Sponsorship PoolOwners (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolContributors (r:1 w:1)
+	/// Proof: Sponsorship PoolContributors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolContributions (r:1 w:1)
+	/// Proof: Sponsorship PoolContributions (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Sponsorship ExpiryQueue (r:1 w:1)
+	/// Proof: Sponsorship ExpiryQueue (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	/// Storage: Sponsorship OwnedPoolCount (r:1 w:1)
+	/// Proof: Sponsorship OwnedPoolCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	fn create_pool() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `76`
 		//  Estimated: `1489`
 		// Minimum execution time: 15_000_000 picoseconds.
 		Weight::from_parts(16_000_000, 1489)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: Sponsorship Pools (r:1 w:1)
 	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship LowBalanceAlerted (r:1 w:1)
+	/// Proof: Sponsorship LowBalanceAlerted (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolContributors (r:1 w:1)
+	/// Proof: Sponsorship PoolContributors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolContributions (r:1 w:1)
+	/// Proof: Sponsorship PoolContributions (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
 	fn fund_pool() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `400`
 		//  Estimated: `3465`
 		// Minimum execution time: 12_000_000 picoseconds.
 		Weight::from_parts(13_000_000, 3465)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Sponsorship Pools (r:1 w:1)
 	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship LowBalanceAlerted (r:1 w:1)
+	/// Proof: Sponsorship LowBalanceAlerted (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Sponsorship ExpiryQueue (r:1 w:1)
+	/// Proof: Sponsorship ExpiryQueue (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRoles (r:1 w:0)
+	/// Proof: Sponsorship PoolRoles (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Sponsorship ConfigUpdateQueue (r:1 w:1)
+	/// Proof: Sponsorship ConfigUpdateQueue (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PendingConfigUpdates (r:0 w:1)
+	/// Proof: Sponsorship PendingConfigUpdates (max_values: None, max_size: Some(800), added: 3275, mode: MaxEncodedLen)
 	fn update_pool_config() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `400`
 		//  Estimated: `3465`
 		// Minimum execution time: 11_000_000 picoseconds.
 		Weight::from_parts(12_000_000, 3465)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Sponsorship Pools (r:1 w:0)
 	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
 	/// Storage: Sponsorship PoolAuthorizations (r:0 w:1)
-	/// Proof: Sponsorship PoolAuthorizations (max_values: None, max_size: Some(500), added: 2975, mode: MaxEncodedLen)
+	/// Proof: Sponsorship PoolAuthorizations (max_values: None, max_size: Some(32870), added: 35345, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRoles (r:1 w:0)
+	/// Proof: Sponsorship PoolRoles (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRuleCount (r:1 w:1)
+	/// Proof: Sponsorship PoolRuleCount (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Sponsorship NextRuleId (r:1 w:1)
+	/// Proof: Sponsorship NextRuleId (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	fn add_authorization_rule() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `400`
 		//  Estimated: `3465`
 		// Minimum execution time: 10_000_000 picoseconds.
 		Weight::from_parts(11_000_000, 3465)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: Sponsorship Pools (r:1 w:0)
 	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
-	/// Storage: Sponsorship PoolAuthorizations (r:0 w:1)
-	/// Proof: Sponsorship PoolAuthorizations (max_values: None, max_size: Some(500), added: 2975, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolAuthorizations (r:1 w:1)
+	/// Proof: Sponsorship PoolAuthorizations (max_values: None, max_size: Some(32870), added: 35345, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRoles (r:1 w:0)
+	/// Proof: Sponsorship PoolRoles (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRuleCount (r:1 w:1)
+	/// Proof: Sponsorship PoolRuleCount (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	fn remove_authorization_rule() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `400`
 		//  Estimated: `3465`
 		// Minimum execution time: 9_000_000 picoseconds.
 		Weight::from_parts(10_000_000, 3465)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Sponsorship Pools (r:1 w:1)
 	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
@@ -110,65 +162,855 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Sponsorship NextTransactionId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: Sponsorship TransactionLog (r:0 w:1)
 	/// Proof: Sponsorship TransactionLog (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolDailyStats (r:1 w:1)
+	/// Proof: Sponsorship PoolDailyStats (max_values: None, max_size: Some(2500), added: 4975, mode: MaxEncodedLen)
+	/// Storage: Sponsorship LowBalanceAlerted (r:1 w:1)
+	/// Proof: Sponsorship LowBalanceAlerted (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Sponsorship AutoTopUps (r:1 w:0)
+	/// Proof: Sponsorship AutoTopUps (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PendingTopUps (r:1 w:1)
+	/// Proof: Sponsorship PendingTopUps (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolInFlight (r:1 w:1)
+	/// Proof: Sponsorship PoolInFlight (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Sponsorship UserSpending (r:1 w:1)
+	/// Proof: Sponsorship UserSpending (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolAllowlistRoots (r:1 w:0)
+	/// Proof: Sponsorship PoolAllowlistRoots (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Sponsorship GlobalDenyList (r:1 w:0)
+	/// Proof: Sponsorship GlobalDenyList (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolDenyList (r:1 w:0)
+	/// Proof: Sponsorship PoolDenyList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn sponsor_transaction() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `476`
-		//  Estimated: `3465`
-		// Minimum execution time: 18_000_000 picoseconds.
-		Weight::from_parts(19_000_000, 3465)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		//  Estimated: `5965`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 5965)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: Sponsorship TransactionLog (r:1 w:1)
 	/// Proof: Sponsorship TransactionLog (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
 	/// Storage: Sponsorship Pools (r:1 w:1)
 	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship UserSpending (r:1 w:1)
+	/// Proof: Sponsorship UserSpending (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolDailyStats (r:1 w:1)
+	/// Proof: Sponsorship PoolDailyStats (max_values: None, max_size: Some(2500), added: 4975, mode: MaxEncodedLen)
+	/// Storage: Sponsorship LowBalanceAlerted (r:1 w:1)
+	/// Proof: Sponsorship LowBalanceAlerted (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolInFlight (r:1 w:1)
+	/// Proof: Sponsorship PoolInFlight (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	fn process_receipt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `700`
+		//  Estimated: `5965`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 5965)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: Sponsorship Pools (r:17 w:1)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship NextTransactionId (r:1 w:1)
+	/// Proof: Sponsorship NextTransactionId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Sponsorship TransactionLog (r:0 w:1)
+	/// Proof: Sponsorship TransactionLog (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolDailyStats (r:1 w:1)
+	/// Proof: Sponsorship PoolDailyStats (max_values: None, max_size: Some(2500), added: 4975, mode: MaxEncodedLen)
+	/// The range of component `c` is `[1, 16]`.
+	/// Storage: Sponsorship LowBalanceAlerted (r:1 w:1)
+	/// Proof: Sponsorship LowBalanceAlerted (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Sponsorship AutoTopUps (r:1 w:0)
+	/// Proof: Sponsorship AutoTopUps (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PendingTopUps (r:1 w:1)
+	/// Proof: Sponsorship PendingTopUps (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolInFlight (r:1 w:1)
+	/// Proof: Sponsorship PoolInFlight (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Sponsorship UserSpending (r:1 w:1)
+	/// Proof: Sponsorship UserSpending (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolAllowlistRoots (r:16 w:0)
+	/// Proof: Sponsorship PoolAllowlistRoots (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Sponsorship GlobalDenyList (r:16 w:0)
+	/// Proof: Sponsorship GlobalDenyList (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolDenyList (r:16 w:0)
+	/// Proof: Sponsorship PoolDenyList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Sponsorship ChainPools (r:17 w:0)
+	/// Proof: Sponsorship ChainPools (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	fn sponsor_transaction_auto(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `476 + c * (400 ±0)`
+		//  Estimated: `5965 + c * (2475 ±0)`
+		// Minimum execution time: 23_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 5965)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(3_500_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 2499).saturating_mul(c.into()))
+	}
+	/// Storage: Sponsorship Pools (r:1 w:0)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship AutoTopUps (r:1 w:1)
+	/// Proof: Sponsorship AutoTopUps (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PendingTopUps (r:1 w:1)
+	/// Proof: Sponsorship PendingTopUps (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRoles (r:1 w:0)
+	/// Proof: Sponsorship PoolRoles (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	fn set_auto_top_up() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `400`
+		//  Estimated: `3569`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3569)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Sponsorship Pools (r:1 w:0)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship AutoTopUps (r:1 w:1)
+	/// Proof: Sponsorship AutoTopUps (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRoles (r:1 w:0)
+	/// Proof: Sponsorship PoolRoles (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	fn clear_auto_top_up() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `450`
+		//  Estimated: `3569`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 3569)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Sponsorship PendingTopUps (r:1 w:1)
+	/// Proof: Sponsorship PendingTopUps (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
+	/// Storage: Sponsorship AutoTopUps (r:16 w:16)
+	/// Proof: Sponsorship AutoTopUps (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Sponsorship Pools (r:16 w:16)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: System Account (r:16 w:16)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Sponsorship LowBalanceAlerted (r:16 w:16)
+	/// Proof: Sponsorship LowBalanceAlerted (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn on_initialize_auto_top_up(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `100 + n * (520 ±0)`
+		//  Estimated: `1614 + n * (2603 ±0)`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_000_000, 1614)
+			// Standard Error: 6_000
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	/// Storage: Sponsorship Pools (r:1 w:1)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolContributions (r:1 w:1)
+	/// Proof: Sponsorship PoolContributions (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolContributors (r:17 w:16)
+	/// Proof: Sponsorship PoolContributors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:16 w:16)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Sponsorship AutoTopUps (r:0 w:1)
+	/// Proof: Sponsorship AutoTopUps (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Sponsorship LowBalanceAlerted (r:0 w:1)
+	/// Proof: Sponsorship LowBalanceAlerted (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PendingPoolOwners (r:0 w:1)
+	/// Proof: Sponsorship PendingPoolOwners (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PendingConfigUpdates (r:0 w:1)
+	/// Proof: Sponsorship PendingConfigUpdates (max_values: None, max_size: Some(800), added: 3275, mode: MaxEncodedLen)
+	/// Storage: Sponsorship OwnedPoolCount (r:1 w:1)
+	/// Proof: Sponsorship OwnedPoolCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolAllowlistRoots (r:0 w:1)
+	/// Proof: Sponsorship PoolAllowlistRoots (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Sponsorship VoucherSigners (r:0 w:1)
+	/// Proof: Sponsorship VoucherSigners (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `c` is `[1, 16]`.
+	fn close_pool(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `520 + c * (190 ±0)`
+		//  Estimated: `3549 + c * (2603 ±0)`
+		// Minimum execution time: 22_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3549)
+			// Standard Error: 5_000
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(c.into()))
+	}
+	/// Storage: Sponsorship Pools (r:1 w:0)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRoles (r:0 w:1)
+	/// Proof: Sponsorship PoolRoles (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	fn grant_pool_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `380`
 		//  Estimated: `3465`
-		// Minimum execution time: 15_000_000 picoseconds.
-		Weight::from_parts(16_000_000, 3465)
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 3465)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Sponsorship Pools (r:1 w:0)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRoles (r:1 w:1)
+	/// Proof: Sponsorship PoolRoles (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	fn revoke_pool_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `430`
+		//  Estimated: `3534`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3534)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Sponsorship Pools (r:1 w:1)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRoles (r:1 w:0)
+	/// Proof: Sponsorship PoolRoles (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	fn pause_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `430`
+		//  Estimated: `3534`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3534)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Sponsorship Pools (r:1 w:1)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRoles (r:1 w:0)
+	/// Proof: Sponsorship PoolRoles (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	fn resume_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `430`
+		//  Estimated: `3534`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3534)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Sponsorship Pools (r:1 w:0)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PendingPoolOwners (r:0 w:1)
+	/// Proof: Sponsorship PendingPoolOwners (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn propose_pool_owner() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `380`
+		//  Estimated: `3465`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 3465)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Sponsorship PendingPoolOwners (r:1 w:1)
+	/// Proof: Sponsorship PendingPoolOwners (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Sponsorship Pools (r:1 w:1)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship OwnedPoolCount (r:2 w:2)
+	/// Proof: Sponsorship OwnedPoolCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolOwners (r:0 w:2)
+	/// Proof: Sponsorship PoolOwners (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolContributors (r:2 w:2)
+	/// Proof: Sponsorship PoolContributors (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolContributions (r:1 w:1)
+	/// Proof: Sponsorship PoolContributions (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Sponsorship AutoTopUps (r:1 w:1)
+	/// Proof: Sponsorship AutoTopUps (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRoles (r:0 w:1)
+	/// Proof: Sponsorship PoolRoles (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolWhitelistDeposit (r:1 w:0)
+	/// Proof: Sponsorship PoolWhitelistDeposit (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Sponsorship VoucherSigners (r:1 w:1)
+	/// Proof: Sponsorship VoucherSigners (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn accept_pool_ownership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `790`
+		//  Estimated: `6196`
+		// Minimum execution time: 40_000_000 picoseconds.
+		Weight::from_parts(42_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	/// Storage: Sponsorship Pools (r:1 w:0)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRoles (r:1 w:0)
+	/// Proof: Sponsorship PoolRoles (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PendingConfigUpdates (r:1 w:1)
+	/// Proof: Sponsorship PendingConfigUpdates (max_values: None, max_size: Some(800), added: 3275, mode: MaxEncodedLen)
+	fn cancel_pool_config_update() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `690`
+		//  Estimated: `4265`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 4265)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Sponsorship ConfigUpdateQueue (r:1 w:1)
+	/// Proof: Sponsorship ConfigUpdateQueue (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PendingConfigUpdates (r:16 w:16)
+	/// Proof: Sponsorship PendingConfigUpdates (max_values: None, max_size: Some(800), added: 3275, mode: MaxEncodedLen)
+	/// Storage: Sponsorship Pools (r:16 w:16)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship LowBalanceAlerted (r:16 w:16)
+	/// Proof: Sponsorship LowBalanceAlerted (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// Storage: Sponsorship ExpiryQueue (r:16 w:16)
+	/// Proof: Sponsorship ExpiryQueue (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn on_initialize_config_updates(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `100 + n * (1180 ±0)`
+		//  Estimated: `3542 + n * (3275 ±0)`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 3542)
+			// Standard Error: 5_000
+			.saturating_add(Weight::from_parts(16_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3275).saturating_mul(n.into()))
+	}
+	/// Storage: Sponsorship VoucherNonceExpiries (r:1 w:1)
+	/// Proof: Sponsorship VoucherNonceExpiries (max_values: None, max_size: Some(205), added: 2680, mode: MaxEncodedLen)
+	/// Storage: Sponsorship UsedVoucherNonces (r:0 w:16)
+	/// Proof: Sponsorship UsedVoucherNonces (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn on_initialize_voucher_nonces(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `80 + n * (12 ±0)`
+		//  Estimated: `3670`
+		// Minimum execution time: 4_000_000 picoseconds.
+		Weight::from_parts(4_000_000, 3670)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: Sponsorship RevocationExpiries (r:1 w:1)
+	/// Proof: Sponsorship RevocationExpiries (max_values: None, max_size: Some(661), added: 3136, mode: MaxEncodedLen)
+	/// Storage: Sponsorship RevokedAttestations (r:0 w:16)
+	/// Proof: Sponsorship RevokedAttestations (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn on_initialize_revocations(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `80 + n * (40 ±0)`
+		//  Estimated: `4126`
+		// Minimum execution time: 4_000_000 picoseconds.
+		Weight::from_parts(4_000_000, 4126)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: Sponsorship PoolAuthorizations (r:8 w:0)
+	/// Proof: Sponsorship PoolAuthorizations (max_values: None, max_size: Some(2150), added: 4625, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:36 w:0)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Sponsorship NftItemUsage (r:32 w:0)
+	/// Proof: Sponsorship NftItemUsage (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Identity IdentityOf (r:3 w:0)
+	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
+	/// The range of component `r` is `[1, 8]`.
+	/// The range of component `n` is `[2, 8]`.
+	fn evaluate_rules(r: u32, n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (120 ±0) + r * (310 ±0)`
+		//  Estimated: `990 + n * (10013 ±0) + r * (24921 ±0)`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(3_000_000, 990)
+			// Standard Error: 3_000
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(r.into()))
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(7_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((9_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 24921).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 10013).saturating_mul(n.into()))
+	}
+	/// Storage: Sponsorship Pools (r:1 w:0)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRoles (r:1 w:0)
+	/// Proof: Sponsorship PoolRoles (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolWhitelist (r:256 w:256)
+	/// Proof: Sponsorship PoolWhitelist (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolWhitelistCount (r:1 w:1)
+	/// Proof: Sponsorship PoolWhitelistCount (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolWhitelistDeposit (r:1 w:1)
+	/// Proof: Sponsorship PoolWhitelistDeposit (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 256]`.
+	fn add_to_whitelist(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `650 + n * (84 ±0)`
+		//  Estimated: `4265 + n * (2559 ±0)`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 4265)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2559).saturating_mul(n.into()))
+	}
+	/// Storage: Sponsorship Pools (r:1 w:0)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRoles (r:1 w:0)
+	/// Proof: Sponsorship PoolRoles (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolWhitelist (r:256 w:256)
+	/// Proof: Sponsorship PoolWhitelist (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolWhitelistCount (r:1 w:1)
+	/// Proof: Sponsorship PoolWhitelistCount (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolWhitelistDeposit (r:1 w:1)
+	/// Proof: Sponsorship PoolWhitelistDeposit (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 256]`.
+	fn remove_from_whitelist(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `650 + n * (84 ±0)`
+		//  Estimated: `4265 + n * (2559 ±0)`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 4265)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2559).saturating_mul(n.into()))
+	}
+	/// Storage: Sponsorship Pools (r:1 w:0)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRoles (r:1 w:0)
+	/// Proof: Sponsorship PoolRoles (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolAllowlistRoots (r:0 w:1)
+	/// Proof: Sponsorship PoolAllowlistRoots (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn set_allowlist_root() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `640`
+		//  Estimated: `3534`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3534)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Sponsorship AllowlistUsage (r:1 w:1)
+	/// Proof: Sponsorship AllowlistUsage (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// The range of component `p` is `[0, 32]`.
+	fn verify_allowlist_proof(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `120`
+		//  Estimated: `3533`
+		// Minimum execution time: 4_000_000 picoseconds.
+		Weight::from_parts(4_000_000, 3533)
+			// Standard Error: 500
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Sponsorship Pools (r:1 w:0)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRoles (r:1 w:0)
+	/// Proof: Sponsorship PoolRoles (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolDenyList (r:1 w:1)
+	/// Proof: Sponsorship PoolDenyList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn add_to_pool_deny_list() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `640`
+		//  Estimated: `4265`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 4265)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Sponsorship Pools (r:1 w:0)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRoles (r:1 w:0)
+	/// Proof: Sponsorship PoolRoles (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolDenyList (r:1 w:1)
+	/// Proof: Sponsorship PoolDenyList (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn remove_from_pool_deny_list() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `640`
+		//  Estimated: `4265`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 4265)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Sponsorship GlobalDenyList (r:1 w:1)
+	/// Proof: Sponsorship GlobalDenyList (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn add_to_global_deny_list() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `150`
+		//  Estimated: `3513`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Sponsorship GlobalDenyList (r:1 w:1)
+	/// Proof: Sponsorship GlobalDenyList (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn remove_from_global_deny_list() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `150`
+		//  Estimated: `3513`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Sponsorship Pools (r:1 w:0)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRoles (r:1 w:0)
+	/// Proof: Sponsorship PoolRoles (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Sponsorship VoucherSigners (r:0 w:1)
+	/// Proof: Sponsorship VoucherSigners (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn set_voucher_signer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `640`
+		//  Estimated: `3534`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3534)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Sponsorship UsedVoucherNonces (r:1 w:1)
+	/// Proof: Sponsorship UsedVoucherNonces (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Sponsorship VoucherSigners (r:1 w:0)
+	/// Proof: Sponsorship VoucherSigners (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Sponsorship VoucherNonceExpiries (r:1 w:1)
+	/// Proof: Sponsorship VoucherNonceExpiries (max_values: None, max_size: Some(205), added: 2680, mode: MaxEncodedLen)
+	fn verify_voucher() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `410`
+		//  Estimated: `3670`
+		// Minimum execution time: 64_000_000 picoseconds.
+		Weight::from_parts(66_000_000, 3670)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Sponsorship Pools (r:1 w:0)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRoles (r:1 w:0)
+	/// Proof: Sponsorship PoolRoles (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolAttesterCount (r:1 w:1)
+	/// Proof: Sponsorship PoolAttesterCount (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolAttesters (r:1 w:1)
+	/// Proof: Sponsorship PoolAttesters (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn add_pool_attester() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `640`
+		//  Estimated: `4265`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 4265)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: Sponsorship Pools (r:1 w:0)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRoles (r:1 w:0)
+	/// Proof: Sponsorship PoolRoles (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolAttesters (r:1 w:1)
+	/// Proof: Sponsorship PoolAttesters (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolAttesterCount (r:1 w:1)
+	/// Proof: Sponsorship PoolAttesterCount (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	fn remove_pool_attester() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `660`
+		//  Estimated: `4265`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 4265)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Sponsorship Pools (r:1 w:0)
+	/// Proof: Sponsorship Pools (max_values: None, max_size: Some(1000), added: 2475, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolRoles (r:1 w:0)
+	/// Proof: Sponsorship PoolRoles (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Sponsorship PoolAttesters (r:2 w:2)
+	/// Proof: Sponsorship PoolAttesters (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn rotate_pool_attester() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `660`
+		//  Estimated: `4265`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 4265)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Sponsorship PoolAttesters (r:1 w:0)
+	/// Proof: Sponsorship PoolAttesters (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Sponsorship RevokedAttestations (r:1 w:1)
+	/// Proof: Sponsorship RevokedAttestations (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Sponsorship RevocationExpiries (r:1 w:1)
+	/// Proof: Sponsorship RevocationExpiries (max_values: None, max_size: Some(661), added: 3136, mode: MaxEncodedLen)
+	fn revoke_attestation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `190`
+		//  Estimated: `4126`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 4126)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Sponsorship PoolAttesters (r:1 w:0)
+	/// Proof: Sponsorship PoolAttesters (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Sponsorship RevokedAttestations (r:1 w:0)
+	/// Proof: Sponsorship RevokedAttestations (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn verify_attestation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `330`
+		//  Estimated: `3533`
+		// Minimum execution time: 60_000_000 picoseconds.
+		Weight::from_parts(61_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	fn create_pool() -> Weight {
 		Weight::from_parts(16_000_000, 1489)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	fn fund_pool() -> Weight {
 		Weight::from_parts(13_000_000, 3465)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	fn update_pool_config() -> Weight {
 		Weight::from_parts(12_000_000, 3465)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	fn add_authorization_rule() -> Weight {
 		Weight::from_parts(11_000_000, 3465)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	fn remove_authorization_rule() -> Weight {
 		Weight::from_parts(10_000_000, 3465)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn sponsor_transaction() -> Weight {
+		Weight::from_parts(21_000_000, 5965)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	fn process_receipt() -> Weight {
+		Weight::from_parts(25_000_000, 5965)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn sponsor_transaction_auto(c: u32, ) -> Weight {
+		Weight::from_parts(22_000_000, 5965)
+			.saturating_add(Weight::from_parts(3_500_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 2499).saturating_mul(c.into()))
+	}
+	fn set_auto_top_up() -> Weight {
+		Weight::from_parts(15_000_000, 3569)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn clear_auto_top_up() -> Weight {
+		Weight::from_parts(12_000_000, 3569)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn on_initialize_auto_top_up(n: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 1614)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
 	}
-	fn sponsor_transaction() -> Weight {
-		Weight::from_parts(19_000_000, 3465)
+	fn close_pool(c: u32, ) -> Weight {
+		Weight::from_parts(18_000_000, 3549)
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(c.into()))
+	}
+	fn grant_pool_role() -> Weight {
+		Weight::from_parts(12_000_000, 3465)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn revoke_pool_role() -> Weight {
+		Weight::from_parts(13_000_000, 3534)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn pause_pool() -> Weight {
+		Weight::from_parts(13_000_000, 3534)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn resume_pool() -> Weight {
+		Weight::from_parts(13_000_000, 3534)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn propose_pool_owner() -> Weight {
+		Weight::from_parts(12_000_000, 3465)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn accept_pool_ownership() -> Weight {
+		Weight::from_parts(42_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	fn cancel_pool_config_update() -> Weight {
+		Weight::from_parts(15_000_000, 4265)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn on_initialize_config_updates(n: u32, ) -> Weight {
+		Weight::from_parts(6_000_000, 3542)
+			.saturating_add(Weight::from_parts(16_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3275).saturating_mul(n.into()))
+	}
+	fn on_initialize_voucher_nonces(n: u32, ) -> Weight {
+		Weight::from_parts(4_000_000, 3670)
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	fn on_initialize_revocations(n: u32, ) -> Weight {
+		Weight::from_parts(4_000_000, 4126)
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	fn evaluate_rules(r: u32, n: u32, ) -> Weight {
+		Weight::from_parts(3_000_000, 990)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(7_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((9_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 24921).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 10013).saturating_mul(n.into()))
+	}
+	fn add_to_whitelist(n: u32, ) -> Weight {
+		Weight::from_parts(18_000_000, 4265)
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2559).saturating_mul(n.into()))
 	}
-	fn process_receipt() -> Weight {
-		Weight::from_parts(16_000_000, 3465)
+	fn remove_from_whitelist(n: u32, ) -> Weight {
+		Weight::from_parts(17_000_000, 4265)
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2559).saturating_mul(n.into()))
+	}
+	fn set_allowlist_root() -> Weight {
+		Weight::from_parts(13_000_000, 3534)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn verify_allowlist_proof(p: u32, ) -> Weight {
+		Weight::from_parts(4_000_000, 3533)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn add_to_pool_deny_list() -> Weight {
+		Weight::from_parts(13_000_000, 4265)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn remove_from_pool_deny_list() -> Weight {
+		Weight::from_parts(13_000_000, 4265)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn add_to_global_deny_list() -> Weight {
+		Weight::from_parts(9_000_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn remove_from_global_deny_list() -> Weight {
+		Weight::from_parts(9_000_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_voucher_signer() -> Weight {
+		Weight::from_parts(13_000_000, 3534)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn verify_voucher() -> Weight {
+		Weight::from_parts(66_000_000, 3670)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn add_pool_attester() -> Weight {
+		Weight::from_parts(14_000_000, 4265)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
-}
\ No newline at end of file
+	fn remove_pool_attester() -> Weight {
+		Weight::from_parts(14_000_000, 4265)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn rotate_pool_attester() -> Weight {
+		Weight::from_parts(15_000_000, 4265)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn revoke_attestation() -> Weight {
+		Weight::from_parts(15_000_000, 4126)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn verify_attestation() -> Weight {
+		Weight::from_parts(61_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+	}
+}
@@ -1,7 +1,7 @@
 use frame_support::pallet_prelude::*;
-use sp_runtime::traits::{Hash, Saturating};
+use sp_runtime::{traits::{Hash, Saturating}, PerThing};
 use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
-use crate::{Config, PoolId, Balance, TransactionStatus};
+use crate::{Config, PoolId, Balance, FeeBreakdown, TransactionStatus};
 
 /// Simplified XCM message structure for hackathon demo
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -201,19 +201,81 @@ pub struct FeeEstimator<T: Config> {
 }
 
 impl<T: Config> FeeEstimator<T> {
+    /// Base fee quoted for chains without a known fee schedule
+    pub const FALLBACK_BASE_FEE: u32 = 1000;
+
+    /// Base execution weight of a sponsored call on the target chain
+    const REMOTE_BASE_WEIGHT: u64 = 200_000_000;
+
+    /// Additional execution weight per byte of call data
+    const REMOTE_WEIGHT_PER_BYTE: u64 = 10_000;
+
     /// Estimate fee for cross-chain transaction
     pub fn estimate_fee(
         target_chain: u32,
         call_data: &[u8],
     ) -> Result<Balance<T>, XcmGatewayError> {
+        Self::estimate_breakdown(target_chain, call_data).map(|breakdown| breakdown.total)
+    }
+
+    /// Estimate fee for cross-chain transaction, split into its components
+    pub fn estimate_breakdown(
+        target_chain: u32,
+        call_data: &[u8],
+    ) -> Result<FeeBreakdown<Balance<T>>, XcmGatewayError> {
         let base_fee = Self::get_base_fee(target_chain)?;
-        let call_fee = Self::calculate_call_fee(call_data);
-        
-        Ok(base_fee.saturating_add(call_fee))
+        Ok(Self::build_breakdown(base_fee, call_data, false))
+    }
+
+    /// Estimate fee, quoting unsupported chains with the fallback base fee
+    pub fn estimate_breakdown_or_fallback(
+        target_chain: u32,
+        call_data: &[u8],
+    ) -> FeeBreakdown<Balance<T>> {
+        Self::estimate_breakdown(target_chain, call_data).unwrap_or_else(|_| {
+            Self::build_breakdown(Self::FALLBACK_BASE_FEE.into(), call_data, true)
+        })
+    }
+
+    /// Estimate execution weight of the call on the target chain
+    pub fn estimate_remote_weight(call_data: &[u8]) -> Weight {
+        let per_byte = Self::REMOTE_WEIGHT_PER_BYTE.saturating_mul(call_data.len() as u64);
+        Weight::from_parts(Self::REMOTE_BASE_WEIGHT.saturating_add(per_byte), 0)
+    }
+
+    /// Assemble all fee components on top of the given base fee
+    fn build_breakdown(
+        base_fee: Balance<T>,
+        call_data: &[u8],
+        used_fallback: bool,
+    ) -> FeeBreakdown<Balance<T>> {
+        use frame_support::weights::WeightToFee;
+
+        let size_fee = Self::calculate_call_fee(call_data);
+        let weight_fee = T::WeightToFee::weight_to_fee(&Self::estimate_remote_weight(call_data));
+        let xcm_delivery_fee = T::XcmDeliveryFee::get();
+
+        let subtotal = base_fee
+            .saturating_add(size_fee)
+            .saturating_add(weight_fee)
+            .saturating_add(xcm_delivery_fee);
+        let protocol_fee = T::ProtocolFee::get().mul_floor(subtotal);
+        let safety_margin = T::FeeSafetyMargin::get().mul_ceil(subtotal);
+
+        FeeBreakdown {
+            base_fee,
+            size_fee,
+            weight_fee,
+            xcm_delivery_fee,
+            protocol_fee,
+            safety_margin,
+            total: subtotal.saturating_add(protocol_fee).saturating_add(safety_margin),
+            used_fallback,
+        }
     }
 
     /// Get base fee for target parachain
-    fn get_base_fee(target_chain: u32) -> Result<Balance<T>, XcmGatewayError> {
+    pub fn get_base_fee(target_chain: u32) -> Result<Balance<T>, XcmGatewayError> {
         let base_fee = match target_chain {
             2000 => Balance::<T>::from(1000u32), // Acala base fee
             2006 => Balance::<T>::from(800u32),  // Astar base fee
@@ -226,7 +288,7 @@ impl<T: Config> FeeEstimator<T> {
     }
 
     /// Calculate fee based on call data complexity
-    fn calculate_call_fee(call_data: &[u8]) -> Balance<T> {
+    pub fn calculate_call_fee(call_data: &[u8]) -> Balance<T> {
         // Simple fee calculation based on call data size
         let size_fee = Balance::<T>::from(call_data.len() as u32);
         size_fee.saturating_mul(Balance::<T>::from(2u32)) // 2 units per byte
@@ -381,6 +443,46 @@ mod tests {
             assert_eq!(result, Err(XcmGatewayError::UnsupportedDestination));
         });
     }
+
+    #[test]
+    fn test_fee_breakdown_components() {
+        new_test_ext().execute_with(|| {
+            XcmDeliveryFee::set(100);
+            ProtocolFee::set(sp_runtime::Permill::from_percent(1));
+            FeeSafetyMargin::set(sp_runtime::Permill::from_percent(10));
+            WeightFeeMultiplier::set(1);
+
+            let call_data = vec![1, 2, 3, 4];
+            let breakdown = FeeEstimator::<Test>::estimate_breakdown(2006, &call_data).unwrap();
+
+            assert_eq!(breakdown.base_fee, 800);
+            assert_eq!(breakdown.size_fee, 8);
+            assert_eq!(breakdown.weight_fee, 200_040_000); // 200_000_000 base + 4 * 10_000 per byte
+            assert_eq!(breakdown.xcm_delivery_fee, 100);
+            let subtotal = 800 + 8 + 200_040_000 + 100;
+            assert_eq!(breakdown.protocol_fee, subtotal / 100);
+            assert_eq!(breakdown.safety_margin, (subtotal + 9) / 10);
+            assert_eq!(
+                breakdown.total,
+                subtotal + breakdown.protocol_fee + breakdown.safety_margin
+            );
+            assert!(!breakdown.used_fallback);
+            assert_eq!(FeeEstimator::<Test>::estimate_fee(2006, &call_data), Ok(breakdown.total));
+        });
+    }
+
+    #[test]
+    fn test_fee_breakdown_fallback_matches_estimator() {
+        new_test_ext().execute_with(|| {
+            let call_data = vec![1, 2, 3, 4];
+            let breakdown = FeeEstimator::<Test>::estimate_breakdown_or_fallback(9999, &call_data);
+
+            assert!(breakdown.used_fallback);
+            assert_eq!(breakdown.base_fee, 1000);
+            assert_eq!(breakdown.size_fee, 8); // Same 2 units per byte as supported chains
+            assert_eq!(breakdown.total, 1008);
+        });
+    }
 }
 
 /// Cross-chain transaction dispatcher
@@ -1036,10 +1138,6 @@ impl<T: Config> ReceiptProcessor<T> {
     /// Validate receipt integrity
     fn validate_receipt(receipt: &TransactionReceipt<T>) -> bool {
         // Basic validation checks
-        if receipt.transaction_id == 0 {
-            return false;
-        }
-
         if receipt.target_chain == 0 {
             return false;
         }
@@ -5,6 +5,9 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+#[cfg(test)]
+mod tests;
+
 use cumulus_pallet_parachain_system::RelayNumberStrictlyIncreases;
 use sp_api::impl_runtime_apis;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
@@ -24,7 +27,7 @@ use frame_support::{
 	construct_runtime,
 	dispatch::DispatchClass,
 	parameter_types,
-	traits::{ConstU128, ConstU32, ConstU64, ConstU8, Everything},
+	traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64, ConstU8, Everything},
 	weights::{
 		constants::WEIGHT_REF_TIME_PER_SECOND, ConstantMultiplier, Weight, WeightToFeeCoefficient,
 		WeightToFeeCoefficients, WeightToFeePolynomial,
@@ -33,7 +36,7 @@ use frame_support::{
 };
 use frame_system::{
 	limits::{BlockLength, BlockWeights},
-	EnsureRoot,
+	EnsureRoot, EnsureSigned,
 };
 pub use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 pub use sp_runtime::{MultiAddress, Perbill, Permill};
@@ -55,6 +58,7 @@ use xcm_builder::{
 use xcm_executor::{Config, XcmExecutor};
 
 // Local module imports
+use pallet_nfts::PalletFeatures;
 use pallet_sponsorship;
 
 /// Alias to 512-bit hash when used in the context of a transaction signature on the chain.
@@ -107,6 +111,9 @@ pub type SignedExtra = (
 pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (pallet_sponsorship::migrations::v1::MigrateToV1<Runtime>,);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -114,6 +121,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 /// Handles converting a weight scalar to a fee value, based on the scale and granularity of the
@@ -171,10 +179,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("gasleap"),
 	impl_name: create_runtime_str!("gasleap"),
 	authoring_version: 1,
-	spec_version: 1,
+	spec_version: 2,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -235,7 +243,8 @@ parameter_types! {
 	pub RuntimeBlockWeights: BlockWeights = BlockWeights::builder()
 		.base_block(BlockExecutionWeight::get())
 		.for_class(DispatchClass::all(), |weights| {
-			weights.base_extrinsic = ExtrinsicBaseWeight::get();
+			// Creating or reaping an account writes `AccountFirstSeen` outside the call's weight
+			weights.base_extrinsic = ExtrinsicBaseWeight::get().saturating_add(RocksDbWeight::get().writes(1));
 		})
 		.for_class(DispatchClass::Normal, |weights| {
 			weights.max_total = Some(NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT);
@@ -285,9 +294,9 @@ impl frame_system::Config for Runtime {
 	/// The data to be stored in an account.
 	type AccountData = pallet_balances::AccountData<Balance>;
 	/// What to do if a new account is created.
-	type OnNewAccount = ();
+	type OnNewAccount = Sponsorship;
 	/// What to do if an account is fully reaped from the system.
-	type OnKilledAccount = ();
+	type OnKilledAccount = Sponsorship;
 	/// The weight of database operations that the runtime can invoke.
 	type DbWeight = RocksDbWeight;
 	/// The basic call filter to use in dispatchable.
@@ -339,6 +348,115 @@ impl pallet_balances::Config for Runtime {
 	type MaxHolds = ();
 }
 
+parameter_types! {
+	pub const AssetDeposit: Balance = 10 * UNIT;
+	pub const AssetAccountDeposit: Balance = MILLIUNIT;
+	pub const MetadataDepositBase: Balance = MILLIUNIT;
+	pub const MetadataDepositPerByte: Balance = 10 * MICROUNIT;
+	pub const ApprovalDeposit: Balance = EXISTENTIAL_DEPOSIT;
+}
+
+impl pallet_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type AssetId = u32;
+	type AssetIdParameter = codec::Compact<u32>;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+parameter_types! {
+	pub NftsFeatures: PalletFeatures = PalletFeatures::all_enabled();
+	pub const NftsCollectionDeposit: Balance = 10 * UNIT;
+	pub const NftsItemDeposit: Balance = UNIT / 100;
+	pub const NftsMetadataDepositBase: Balance = MILLIUNIT;
+	pub const NftsAttributeDepositBase: Balance = MILLIUNIT;
+	pub const NftsDepositPerByte: Balance = 10 * MICROUNIT;
+	pub const NftsMaxDeadlineDuration: BlockNumber = 12 * 30 * DAYS;
+}
+
+impl pallet_nfts::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type Locker = ();
+	type CollectionDeposit = NftsCollectionDeposit;
+	type ItemDeposit = NftsItemDeposit;
+	type MetadataDepositBase = NftsMetadataDepositBase;
+	type AttributeDepositBase = NftsAttributeDepositBase;
+	type DepositPerByte = NftsDepositPerByte;
+	type StringLimit = ConstU32<256>;
+	type KeyLimit = ConstU32<64>;
+	type ValueLimit = ConstU32<256>;
+	type ApprovalsLimit = ConstU32<20>;
+	type ItemAttributesApprovalsLimit = ConstU32<20>;
+	type MaxTips = ConstU32<10>;
+	type MaxDeadlineDuration = NftsMaxDeadlineDuration;
+	type MaxAttributesPerCall = ConstU32<10>;
+	type Features = NftsFeatures;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type WeightInfo = pallet_nfts::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+}
+
+parameter_types! {
+	pub const IdentityBasicDeposit: Balance = 10 * UNIT;
+	pub const IdentityFieldDeposit: Balance = 250 * MILLIUNIT;
+	pub const IdentitySubAccountDeposit: Balance = 2 * UNIT;
+}
+
+impl pallet_identity::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type BasicDeposit = IdentityBasicDeposit;
+	type FieldDeposit = IdentityFieldDeposit;
+	type SubAccountDeposit = IdentitySubAccountDeposit;
+	type MaxSubAccounts = ConstU32<100>;
+	type MaxAdditionalFields = ConstU32<100>;
+	type MaxRegistrars = ConstU32<20>;
+	type Slashed = ();
+	type ForceOrigin = EnsureRoot<AccountId>;
+	/// Registrars are added through sudo
+	type RegistrarOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
+}
+
+/// Answers `IdentityJudgement` sponsorship rules from `pallet_identity`
+pub struct IdentityJudgements;
+
+impl pallet_sponsorship::InspectIdentity<AccountId> for IdentityJudgements {
+	fn has_good_judgement(who: &AccountId, registrars: &[u32]) -> bool {
+		Identity::identity(who).map_or(false, |registration| {
+			registration.judgements.iter().any(|(registrar, judgement)| {
+				registrars.contains(registrar) &&
+					matches!(
+						judgement,
+						pallet_identity::Judgement::KnownGood | pallet_identity::Judgement::Reasonable
+					)
+			})
+		})
+	}
+}
+
 parameter_types! {
 	/// Relay Chain `TransactionByteFee` / 10
 	pub const TransactionByteFee: Balance = 10 * MICROUNIT;
@@ -601,8 +719,103 @@ impl cumulus_pallet_xcm::Config for Runtime {
 parameter_types! {
 	pub const SponsorshipPalletId: PalletId = PalletId(*b"gasleap!");
 	pub const MaxPoolsPerAccount: u32 = 100;
-	pub const MaxAuthorizationsPerPool: u32 = 1000;
+	// Every rule of a pool is evaluated when sponsoring, so these bound its weight
+	pub const MaxAuthorizationsPerPool: u32 = 8;
+	pub const MaxRuleNodes: u32 = 8;
+	pub const MaxRuleDepth: u32 = 4;
 	pub const MinPoolDeposit: Balance = 100 * UNIT;
+	pub const WhitelistDepositPerAccount: Balance = 10 * MILLIUNIT;
+	pub const MaxWhitelistBatch: u32 = 256;
+	pub const MaxMerkleProofDepth: u32 = 32;
+	pub const MaxAttestersPerPool: u32 = 16;
+	pub const MaxAutoSelectCandidates: u32 = 4;
+	pub const SponsorshipXcmDeliveryFee: Balance = MILLIUNIT;
+	pub const SponsorshipProtocolFee: Permill = Permill::from_percent(1);
+	pub const SponsorshipFeeSafetyMargin: Permill = Permill::from_percent(10);
+	pub const SponsorshipBlocksPerDay: BlockNumber = DAYS;
+	pub const MaxPendingTopUps: u32 = 32;
+	pub const MaxContributorsPerPool: u32 = 100;
+	pub const MaxExpiriesPerBlock: u32 = 16;
+	pub const MaxVoucherExpiriesPerBlock: u32 = 16;
+	pub const MaxRevocationExpiriesPerBlock: u32 = 16;
+	pub const MaxConfigUpdatesPerBlock: u32 = 16;
+	pub const SponsorshipMaxNftItemsChecked: u32 = 4;
+}
+
+/// Signs sponsorship vouchers and attestations with a fixed sr25519 key, and mints NFTs
+/// and judges identities through the real pallets, for benchmarks
+#[cfg(feature = "runtime-benchmarks")]
+pub struct SponsorshipBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_sponsorship::BenchmarkHelper<AccountId, Signature, u32> for SponsorshipBenchmarkHelper {
+	fn sign(message: &[u8]) -> (AccountId, Signature) {
+		let public = sp_io::crypto::sr25519_generate(0.into(), Some(b"//Sponsorship".to_vec()));
+		let signature = sp_io::crypto::sr25519_sign(0.into(), &public, message)
+			.expect("key was just generated; qed");
+		(sp_runtime::MultiSigner::Sr25519(public).into_account(), Signature::Sr25519(signature))
+	}
+
+	fn mint_nfts(who: &AccountId, items: u32) -> u32 {
+		use frame_support::traits::Currency;
+
+		// Item deposits are reserved from the collection owner
+		let _ = Balances::deposit_creating(who, 1_000 * UNIT);
+		let collection = pallet_nfts::NextCollectionId::<Runtime>::get().unwrap_or_default();
+		let config = pallet_nfts::CollectionConfig {
+			settings: pallet_nfts::CollectionSettings::all_enabled(),
+			max_supply: None,
+			mint_settings: Default::default(),
+		};
+		Nfts::force_create(RuntimeOrigin::root(), who.clone().into(), config)
+			.expect("root may create collections; qed");
+		for item in 0..items {
+			Nfts::force_mint(
+				RuntimeOrigin::signed(who.clone()),
+				collection,
+				item,
+				who.clone().into(),
+				Default::default(),
+			)
+			.expect("the collection owner is its issuer; qed");
+		}
+		collection
+	}
+
+	fn judge_identity(who: &AccountId) -> u32 {
+		use frame_support::traits::Currency;
+		use sp_runtime::traits::Hash;
+
+		let registrar = AccountId::from([0xaa; 32]);
+		Identity::add_registrar(RuntimeOrigin::root(), registrar.clone().into())
+			.expect("root may add registrars; qed");
+		let index = Identity::registrars().len() as u32 - 1;
+
+		// The identity deposit is reserved from `who`
+		let _ = Balances::deposit_creating(who, 1_000 * UNIT);
+		let info = pallet_identity::IdentityInfo {
+			additional: Default::default(),
+			display: pallet_identity::Data::Raw(b"gasleap".to_vec().try_into().expect("7 bytes fit; qed")),
+			legal: pallet_identity::Data::None,
+			web: pallet_identity::Data::None,
+			riot: pallet_identity::Data::None,
+			email: pallet_identity::Data::None,
+			pgp_fingerprint: None,
+			image: pallet_identity::Data::None,
+			twitter: pallet_identity::Data::None,
+		};
+		Identity::set_identity(RuntimeOrigin::signed(who.clone()), Box::new(info.clone()))
+			.expect("`who` can pay the deposit; qed");
+		Identity::provide_judgement(
+			RuntimeOrigin::signed(registrar),
+			index,
+			who.clone().into(),
+			pallet_identity::Judgement::KnownGood,
+			BlakeTwo256::hash_of(&info),
+		)
+		.expect("the registrar judges the identity just set; qed");
+		index
+	}
 }
 
 impl pallet_sponsorship::Config for Runtime {
@@ -611,7 +824,37 @@ impl pallet_sponsorship::Config for Runtime {
 	type PalletId = SponsorshipPalletId;
 	type MaxPoolsPerAccount = MaxPoolsPerAccount;
 	type MaxAuthorizationsPerPool = MaxAuthorizationsPerPool;
+	type MaxRuleNodes = MaxRuleNodes;
+	type MaxRuleDepth = MaxRuleDepth;
 	type MinPoolDeposit = MinPoolDeposit;
+	type WhitelistDepositPerAccount = WhitelistDepositPerAccount;
+	type MaxWhitelistBatch = MaxWhitelistBatch;
+	type MaxMerkleProofDepth = MaxMerkleProofDepth;
+	type MaxAttestersPerPool = MaxAttestersPerPool;
+	type MaxAutoSelectCandidates = MaxAutoSelectCandidates;
+	type WeightToFee = WeightToFee;
+	type XcmDeliveryFee = SponsorshipXcmDeliveryFee;
+	type ProtocolFee = SponsorshipProtocolFee;
+	type FeeSafetyMargin = SponsorshipFeeSafetyMargin;
+	type BlocksPerDay = SponsorshipBlocksPerDay;
+	type MaxPendingTopUps = MaxPendingTopUps;
+	type MaxContributorsPerPool = MaxContributorsPerPool;
+	type MaxExpiriesPerBlock = MaxExpiriesPerBlock;
+	type MaxVoucherExpiriesPerBlock = MaxVoucherExpiriesPerBlock;
+	type MaxRevocationExpiriesPerBlock = MaxRevocationExpiriesPerBlock;
+	type MaxConfigUpdatesPerBlock = MaxConfigUpdatesPerBlock;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	/// Receipts are relayed through sudo until target chains report them over XCM
+	type ReceiptOrigin = EnsureRoot<AccountId>;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = SponsorshipBenchmarkHelper;
+	type Assets = Assets;
+	type Nfts = pallet_sponsorship::EnumerableNfts<Nfts>;
+	type MaxNftItemsChecked = SponsorshipMaxNftItemsChecked;
+	type Identity = IdentityJudgements;
+	type UnixTime = Timestamp;
 	type WeightInfo = ();
 }
 
@@ -632,6 +875,9 @@ construct_runtime!(
 		// Monetary stuff.
 		Balances: pallet_balances,
 		TransactionPayment: pallet_transaction_payment,
+		Assets: pallet_assets,
+		Nfts: pallet_nfts,
+		Identity: pallet_identity,
 
 		// Governance
 		Sudo: pallet_sudo,
@@ -664,6 +910,9 @@ mod benches {
 		[frame_benchmarking, BaselineBench::<Runtime>]
 		[frame_system, SystemBench::<Runtime>]
 		[pallet_balances, Balances]
+		[pallet_assets, Assets]
+		[pallet_nfts, Nfts]
+		[pallet_identity, Identity]
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_timestamp, Timestamp]
 		[pallet_collator_selection, CollatorSelection]
@@ -793,6 +1042,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl gasleap_sponsorship_rpc_runtime_api::SponsorshipApi<Block, AccountId, Balance, BlockNumber> for Runtime {
 		fn get_pool(pool_id: pallet_sponsorship::PoolId) -> Option<pallet_sponsorship::PoolInfo<AccountId, Balance, BlockNumber>> {
 			Sponsorship::get_pool_info(pool_id).map(|pool_info| pallet_sponsorship::PoolInfo {
@@ -835,6 +1085,19 @@ impl_runtime_apis! {
 				.collect()
 		}
 
+		fn get_transaction(transaction_id: u64) -> Option<pallet_sponsorship::TransactionRecord<AccountId, Balance, BlockNumber>> {
+			Sponsorship::get_transaction_record(transaction_id).map(|tx_record| pallet_sponsorship::TransactionRecord {
+				id: tx_record.id,
+				pool_id: tx_record.pool_id,
+				user: tx_record.user,
+				target_chain: tx_record.target_chain,
+				call_hash: tx_record.call_hash,
+				gas_cost: tx_record.gas_cost,
+				status: tx_record.status,
+				timestamp: tx_record.timestamp,
+			})
+		}
+
 		fn get_user_gas_savings(user: AccountId) -> Balance {
 			Sponsorship::get_user_total_gas_savings(&user)
 		}
@@ -842,6 +1105,67 @@ impl_runtime_apis! {
 		fn estimate_gas_cost(target_chain: u32, call_data: Vec<u8>) -> Balance {
 			Sponsorship::estimate_transaction_gas_cost(target_chain, &call_data)
 		}
+
+		fn estimate_fee_breakdown(target_chain: u32, call_data: Vec<u8>) -> pallet_sponsorship::FeeBreakdown<Balance> {
+			Sponsorship::estimate_fee_breakdown(target_chain, &call_data)
+		}
+
+		fn get_pool_stats(
+			pool_id: pallet_sponsorship::PoolId,
+			from_block: Option<BlockNumber>,
+			to_block: Option<BlockNumber>,
+		) -> pallet_sponsorship::PoolStats<Balance> {
+			Sponsorship::get_pool_stats(pool_id, from_block, to_block)
+		}
+
+		fn forecast_pool_runway(
+			pool_id: pallet_sponsorship::PoolId,
+			target_runway_days: u32,
+		) -> Option<pallet_sponsorship::PoolForecast<Balance, BlockNumber>> {
+			Sponsorship::forecast_pool_runway(pool_id, target_runway_days)
+		}
+
+		fn next_sponsorship_window(
+			pool_id: pallet_sponsorship::PoolId,
+		) -> Option<pallet_sponsorship::SponsorshipWindow<BlockNumber>> {
+			Sponsorship::next_sponsorship_window(pool_id)
+		}
+
+		fn dry_run_sponsorship(
+			user: AccountId,
+			pool_id: pallet_sponsorship::PoolId,
+			target_chain: u32,
+			call_data: Vec<u8>,
+			credential: Option<Vec<u8>>,
+		) -> pallet_sponsorship::DryRunResult<Balance> {
+			Sponsorship::dry_run_sponsorship(user, pool_id, target_chain, call_data, credential)
+		}
+
+		fn get_transaction_history_paged(
+			pool_id: pallet_sponsorship::PoolId,
+			cursor: Option<u64>,
+			limit: u32,
+		) -> (Vec<pallet_sponsorship::TransactionRecord<AccountId, Balance, BlockNumber>>, Option<u64>) {
+			let (records, next_cursor) = Sponsorship::get_pool_transaction_page(pool_id, cursor, limit);
+			let records = records
+				.into_iter()
+				.map(|tx_record| pallet_sponsorship::TransactionRecord {
+					id: tx_record.id,
+					pool_id: tx_record.pool_id,
+					user: tx_record.user,
+					target_chain: tx_record.target_chain,
+					call_hash: tx_record.call_hash,
+					gas_cost: tx_record.gas_cost,
+					status: tx_record.status,
+					timestamp: tx_record.timestamp,
+				})
+				.collect();
+			(records, next_cursor)
+		}
+
+		fn get_whitelist_count(pool_id: pallet_sponsorship::PoolId) -> u32 {
+			Sponsorship::pool_whitelist_count(pool_id)
+		}
 	}
 
 	#[cfg(feature = "try-runtime")]
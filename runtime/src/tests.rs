@@ -0,0 +1,76 @@
+use crate::*;
+use frame_support::{assert_ok, traits::GenesisBuild};
+use pallet_sponsorship::InspectIdentity;
+use sp_runtime::traits::Hash;
+
+fn new_test_ext(endowed: &AccountId) -> sp_io::TestExternalities {
+	// Only the pallets involved; the full genesis needs collators to start a session
+	let mut storage = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+	BalancesConfig { balances: vec![(endowed.clone(), 1_000 * UNIT)] }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+	storage.into()
+}
+
+fn identity() -> pallet_identity::IdentityInfo<ConstU32<100>> {
+	pallet_identity::IdentityInfo {
+		additional: Default::default(),
+		display: pallet_identity::Data::Raw(b"gasleap".to_vec().try_into().unwrap()),
+		legal: pallet_identity::Data::None,
+		web: pallet_identity::Data::None,
+		riot: pallet_identity::Data::None,
+		email: pallet_identity::Data::None,
+		pgp_fingerprint: None,
+		image: pallet_identity::Data::None,
+		twitter: pallet_identity::Data::None,
+	}
+}
+
+#[test]
+fn registrar_judgements_satisfy_identity_rules() {
+	let registrar = AccountId::from([1; 32]);
+	let who = AccountId::from([2; 32]);
+
+	new_test_ext(&who).execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), registrar.clone().into()));
+		assert_eq!(Identity::registrars().len(), 1);
+		assert_eq!(Identity::registrars()[0].as_ref().map(|r| r.account.clone()), Some(registrar.clone()));
+
+		let info = identity();
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(who.clone()), Box::new(info.clone())));
+		assert!(!IdentityJudgements::has_good_judgement(&who, &[0]));
+
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(who.clone()), 0, 0));
+		assert!(!IdentityJudgements::has_good_judgement(&who, &[0]));
+
+		let judge = |judgement| {
+			Identity::provide_judgement(
+				RuntimeOrigin::signed(registrar.clone()),
+				0,
+				who.clone().into(),
+				judgement,
+				BlakeTwo256::hash_of(&info),
+			)
+		};
+
+		assert_ok!(judge(pallet_identity::Judgement::Reasonable));
+		assert!(IdentityJudgements::has_good_judgement(&who, &[0]));
+		// Only the registrars a rule names count
+		assert!(!IdentityJudgements::has_good_judgement(&who, &[1]));
+
+		assert_ok!(judge(pallet_identity::Judgement::LowQuality));
+		assert!(!IdentityJudgements::has_good_judgement(&who, &[0]));
+
+		assert_ok!(judge(pallet_identity::Judgement::KnownGood));
+		assert!(IdentityJudgements::has_good_judgement(&who, &[0]));
+
+		// Changing the identity drops its judgements
+		let renamed = pallet_identity::IdentityInfo {
+			display: pallet_identity::Data::Raw(b"renamed".to_vec().try_into().unwrap()),
+			..info
+		};
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(who.clone()), Box::new(renamed)));
+		assert!(!IdentityJudgements::has_good_judgement(&who, &[0]));
+	});
+}